@@ -1,5 +1,6 @@
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![recursion_limit = "512"]
 extern crate alloc;
 
 pub mod stablecoin;
@@ -1,27 +1,42 @@
 //! CEP-18 Casper Fungible Token standard implementation.
 use odra::prelude::*;
-use odra::{casper_types::U256, Address, Mapping, SubModule, UnwrapOrRevert, Var};
+use odra::{casper_types::U256, Address, SubModule, UnwrapOrRevert, Var};
 
 mod tests;
 pub mod errors;
 pub mod events;
+pub mod interfaces;
 pub mod storage;
 pub mod utils;
 
 use crate::stablecoin::errors::Error;
 
 use crate::stablecoin::events::{
-    Blacklist, BlacklisterChanged, Burn, ControllerConfigured, ControllerRemoved,
-    DecreaseAllowance, IncreaseAllowance, Mint, MinterConfigured, MinterRemoved, Paused,
-    SetAllowance, Transfer, TransferFrom, Unblacklist, Unpaused,
+    Allowlisted, AuthorizationCanceled, AuthorizationUsed, Blacklist, BlacklisterChanged, Burn,
+    ControllerConfigured, ControllerRemoved, DecreaseAllowance, Frozen, IncreaseAllowance,
+    MaxSupplyChanged, Mint, MinterConfigured, MinterRemoved, OperationPaused, OperationUnpaused,
+    Paused, RoleTransferAccepted, RoleTransferCancelled, RoleTransferProposed, Seized,
+    SetAllowance, TokensRescued, Transfer, TransferFrom, TransferWithMemo, Unallowlisted,
+    Unblacklist, Unfrozen, Unpaused,
+};
+use crate::stablecoin::interfaces::{
+    Cep18TokenContractRef, StablecoinApprovalReceiverContractRef, StablecoinReceiverContractRef,
 };
 use crate::stablecoin::storage::Roles::{self, Role};
 use crate::stablecoin::storage::{
-    StablecoinAllowancesStorage, StablecoinBalancesStorage, StablecoinDecimalsStorage,
-    StablecoinMinterAllowancesStorage, StablecoinNameStorage, StablecoinRoles,
-    StablecoinSymbolStorage, StablecoinTotalSupplyStorage,
+    StablecoinAllowancesStorage, StablecoinBalancesStorage, StablecoinControllers,
+    StablecoinDecimalsStorage, StablecoinMinterAllowancesStorage, StablecoinNameStorage,
+    StablecoinProposals, StablecoinRedemptions, StablecoinRoles, StablecoinSignatures,
+    StablecoinSymbolStorage, StablecoinTimelock, StablecoinTotalSupplyStorage,
+};
+use crate::stablecoin::utils::{
+    cancel_authorization_message, permit_message, receive_authorization_message,
+    transfer_authorization_message, AdminAction, BlacklistEntry, MintWindow, PausableOperation,
+    PauseState, PendingRoleTransfer, Proposal, QueuedAction, Redemption, StablecoinModality,
+    MAX_MEMO_LENGTH, ROLE_TRANSFER_LIFETIME,
 };
-use crate::stablecoin::utils::StablecoinModality;
+use odra::casper_types::bytesrepr::Bytes;
+use odra::casper_types::PublicKey;
 
 /// CEP-18 token module
 #[odra::module(events = [Mint, Burn, SetAllowance, IncreaseAllowance, DecreaseAllowance, Transfer, TransferFrom])]
@@ -34,10 +49,12 @@ pub struct Stablecoin {
     allowances: SubModule<StablecoinAllowancesStorage>,
     minter_allowances: SubModule<StablecoinMinterAllowancesStorage>,
     roles: SubModule<StablecoinRoles>,
-    controllers: Mapping<Address, Address>,
-    // The Blacklister for this Contract
-    blacklister: Var<Address>,
-    paused: Var<bool>,
+    proposals: SubModule<StablecoinProposals>,
+    timelock: SubModule<StablecoinTimelock>,
+    controllers: SubModule<StablecoinControllers>,
+    signatures: SubModule<StablecoinSignatures>,
+    redemptions: SubModule<StablecoinRedemptions>,
+    paused: Var<PauseState>,
     /// This stores all Stablecoin Roles (MasterMinters, Owners, Pauser, ...)
     modality: Var<StablecoinModality>,
 }
@@ -87,6 +104,7 @@ impl Stablecoin {
         }
 
         self.roles.configure_role(&Roles::Blacklister, &blacklister);
+        self.roles.set_blacklister(&blacklister);
 
         // set the modality
         if let Some(modality) = modality {
@@ -126,13 +144,15 @@ impl Stablecoin {
 
     /// Approves the spender to spend the given amount of tokens on behalf of the caller.
     pub fn approve(&mut self, spender: &Address, amount: &U256) {
-        self.require_unpaused();
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
-        self.require_not_role(spender, &Roles::Blacklisted);
+        self.require_unpaused(PausableOperation::Approve);
+        self.require_not_blacklisted(&self.caller());
+        self.require_not_blacklisted(spender);
         let owner = self.env().caller();
         if owner == *spender {
             self.env().revert(Error::CannotTargetSelfUser);
         }
+        self.require_allowlisted(&owner);
+        self.require_allowlisted(spender);
 
         self.allowances.set(&owner, spender, *amount);
         self.env().emit_event(SetAllowance {
@@ -142,8 +162,23 @@ impl Stablecoin {
         });
     }
 
+    /// Approves the spender and, if the spender is a contract, notifies it through
+    /// `on_approval_received`. Reverts if the spender rejects the allowance.
+    pub fn approve_and_call(&mut self, spender: &Address, amount: &U256, data: Bytes) {
+        let owner = self.caller();
+        self.approve(spender, amount);
+        if spender.is_contract() {
+            let accepted = StablecoinApprovalReceiverContractRef::new(self.env(), *spender)
+                .on_approval_received(owner, *amount, data);
+            if !accepted {
+                self.env().revert(Error::ApprovalRejected);
+            }
+        }
+    }
+
     /// Decreases the allowance of the spender by the given amount.
     pub fn decrease_allowance(&mut self, spender: &Address, decr_by: &U256) {
+        self.require_unpaused(PausableOperation::Approve);
         let owner = self.env().caller();
         let allowance = self.allowance(&owner, spender);
         self.allowances
@@ -158,10 +193,13 @@ impl Stablecoin {
 
     /// Increases the allowance of the spender by the given amount.
     pub fn increase_allowance(&mut self, spender: &Address, inc_by: &U256) {
+        self.require_unpaused(PausableOperation::Approve);
         let owner = self.env().caller();
         if owner == *spender {
             self.env().revert(Error::CannotTargetSelfUser);
         }
+        self.require_allowlisted(&owner);
+        self.require_allowlisted(spender);
         let allowance = self.allowances.get_or_default(&owner, spender);
 
         self.allowances
@@ -174,6 +212,139 @@ impl Stablecoin {
         });
     }
 
+    /// Sets the allowance of the spender on behalf of the owner, authorized by the owner's signature
+    /// over the message built by `utils::permit_message`.
+    pub fn permit(
+        &mut self,
+        owner_public_key: PublicKey,
+        spender: &Address,
+        value: U256,
+        deadline: u64,
+        nonce: u64,
+        signature: Bytes,
+    ) {
+        self.require_unpaused(PausableOperation::Approve);
+        let owner = Address::Account(owner_public_key.to_account_hash());
+        self.require_not_blacklisted(&owner);
+        self.require_not_blacklisted(spender);
+        if owner == *spender {
+            self.env().revert(Error::CannotTargetSelfUser);
+        }
+        self.require_allowlisted(&owner);
+        self.require_allowlisted(spender);
+        if self.env().get_block_time() > deadline {
+            self.env().revert(Error::PermitExpired);
+        }
+        let message = permit_message(
+            &self.env().self_address(),
+            &owner,
+            spender,
+            &value,
+            deadline,
+            nonce,
+        )
+        .unwrap_or_revert(&self.env());
+        self.require_signature(&message, &signature, &owner_public_key);
+        self.signatures.use_nonce(&owner, nonce);
+
+        self.allowances.set(&owner, spender, value);
+        self.env().emit_event(SetAllowance {
+            owner,
+            spender: *spender,
+            allowance: value,
+        });
+    }
+
+    /// Transfers tokens on behalf of `from`, authorized by their signature over the message
+    /// built by `utils::transfer_authorization_message`. Anyone can submit the authorization.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_with_authorization(
+        &mut self,
+        from_public_key: PublicKey,
+        to: &Address,
+        value: U256,
+        valid_after: u64,
+        valid_before: u64,
+        nonce: [u8; 32],
+        signature: Bytes,
+    ) {
+        let from = Address::Account(from_public_key.to_account_hash());
+        let message = transfer_authorization_message(
+            &self.env().self_address(),
+            &from,
+            to,
+            &value,
+            valid_after,
+            valid_before,
+            &nonce,
+        )
+        .unwrap_or_revert(&self.env());
+        self.use_transfer_authorization(
+            &from_public_key,
+            &message,
+            &signature,
+            valid_after,
+            valid_before,
+            &nonce,
+        );
+        self.transfer_authorized(&from, to, &value);
+    }
+
+    /// Collects tokens from `from`, authorized by their signature over the message
+    /// built by `utils::receive_authorization_message`. Only the recipient can submit it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn receive_with_authorization(
+        &mut self,
+        from_public_key: PublicKey,
+        to: &Address,
+        value: U256,
+        valid_after: u64,
+        valid_before: u64,
+        nonce: [u8; 32],
+        signature: Bytes,
+    ) {
+        if self.caller() != *to {
+            self.env().revert(Error::InvalidPayee);
+        }
+        let from = Address::Account(from_public_key.to_account_hash());
+        let message = receive_authorization_message(
+            &self.env().self_address(),
+            &from,
+            to,
+            &value,
+            valid_after,
+            valid_before,
+            &nonce,
+        )
+        .unwrap_or_revert(&self.env());
+        self.use_transfer_authorization(
+            &from_public_key,
+            &message,
+            &signature,
+            valid_after,
+            valid_before,
+            &nonce,
+        );
+        self.transfer_authorized(&from, to, &value);
+    }
+
+    /// Cancels an unused authorization, authorized by the authorizer's signature over the message
+    /// built by `utils::cancel_authorization_message`.
+    pub fn cancel_authorization(
+        &mut self,
+        authorizer_public_key: PublicKey,
+        nonce: [u8; 32],
+        signature: Bytes,
+    ) {
+        let authorizer = Address::Account(authorizer_public_key.to_account_hash());
+        let message = cancel_authorization_message(&self.env().self_address(), &authorizer, &nonce)
+            .unwrap_or_revert(&self.env());
+        self.require_signature(&message, &signature, &authorizer_public_key);
+        self.signatures.use_authorization(&authorizer, &nonce);
+        self.env()
+            .emit_event(AuthorizationCanceled { authorizer, nonce });
+    }
+
     /// Transfers tokens from the caller to the recipient.
     pub fn transfer(&mut self, recipient: &Address, amount: &U256) {
         let caller = self.env().caller();
@@ -185,8 +356,8 @@ impl Stablecoin {
 
     /// Transfers tokens from the owner to the recipient using the spender's allowance.
     pub fn transfer_from(&mut self, owner: &Address, recipient: &Address, amount: &U256) {
-        self.require_not_role(owner, &Roles::Blacklisted);
-        self.require_not_role(recipient, &Roles::Blacklisted);
+        self.require_not_blacklisted(owner);
+        self.require_not_blacklisted(recipient);
         let spender = self.env().caller();
         if owner == recipient {
             self.env().revert(Error::CannotTargetSelfUser);
@@ -197,7 +368,7 @@ impl Stablecoin {
         let allowance = self.allowance(owner, &spender);
         self.allowances.set(
             owner,
-            recipient,
+            &spender,
             allowance
                 .checked_sub(*amount)
                 .unwrap_or_revert_with(&self.env(), Error::InsufficientAllowance),
@@ -211,10 +382,93 @@ impl Stablecoin {
         self.raw_transfer(owner, recipient, amount);
     }
 
+    /// Transfers tokens from the caller to the recipient and, if the recipient is a contract,
+    /// notifies it through `on_stablecoin_received`. Reverts if the recipient rejects the tokens.
+    pub fn transfer_and_call(&mut self, recipient: &Address, amount: &U256, data: Bytes) {
+        let caller = self.caller();
+        self.transfer(recipient, amount);
+        if recipient.is_contract() {
+            let accepted = StablecoinReceiverContractRef::new(self.env(), *recipient)
+                .on_stablecoin_received(caller, caller, *amount, data);
+            if !accepted {
+                self.env().revert(Error::TransferRejected);
+            }
+        }
+    }
+
+    /// Transfers tokens from the caller to the recipient, attaching a payment reference.
+    pub fn transfer_with_memo(&mut self, recipient: &Address, amount: &U256, memo: String) {
+        self.require_memo_length(&memo);
+        self.transfer(recipient, amount);
+        self.env().emit_event(TransferWithMemo {
+            sender: self.caller(),
+            recipient: *recipient,
+            amount: *amount,
+            memo,
+        });
+    }
+
+    /// Transfers tokens from the owner to the recipient using the spender's allowance,
+    /// attaching a payment reference.
+    pub fn transfer_from_with_memo(
+        &mut self,
+        owner: &Address,
+        recipient: &Address,
+        amount: &U256,
+        memo: String,
+    ) {
+        self.require_memo_length(&memo);
+        self.transfer_from(owner, recipient, amount);
+        self.env().emit_event(TransferWithMemo {
+            sender: *owner,
+            recipient: *recipient,
+            amount: *amount,
+            memo,
+        });
+    }
+
+    /// Transfers tokens from the caller to every recipient of the batch, reverting the whole batch
+    /// if any leg fails.
+    pub fn transfer_batch(&mut self, transfers: Vec<(Address, U256)>) {
+        let caller = self.caller();
+        self.require_not_blacklisted(&caller);
+        if self.batch_total(&caller, &transfers) > self.balances.available(&caller) {
+            self.env().revert(Error::InsufficientBalance);
+        }
+        for (recipient, amount) in transfers.iter() {
+            self.raw_transfer(&caller, recipient, amount);
+        }
+    }
+
+    /// Transfers tokens from the owner to every recipient of the batch using the spender's allowance,
+    /// reverting the whole batch if any leg fails.
+    pub fn transfer_from_batch(&mut self, owner: &Address, transfers: Vec<(Address, U256)>) {
+        let spender = self.caller();
+        self.require_not_blacklisted(owner);
+        let allowance = self.allowance(owner, &spender);
+        let total = self.batch_total(owner, &transfers);
+        if total > allowance {
+            self.env().revert(Error::InsufficientAllowance);
+        }
+        if self.balances.available(owner) < total {
+            self.env().revert(Error::InsufficientBalance);
+        }
+        self.allowances.set(owner, &spender, allowance - total);
+        for (recipient, amount) in transfers.iter() {
+            self.env().emit_event(TransferFrom {
+                spender,
+                owner: *owner,
+                recipient: *recipient,
+                amount: *amount,
+            });
+            self.raw_transfer(owner, recipient, amount);
+        }
+    }
+
     /// Burns the given amount of tokens from the given address.
     pub fn burn(&mut self, amount: U256) {
         self.assert_burn_and_mint_enabled();
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
+        self.require_not_blacklisted(&self.caller());
         self.require_role(&self.caller(), &Roles::Minter);
         if amount == U256::zero() {
             self.env().revert(Error::InvalidAmount)
@@ -222,136 +476,559 @@ impl Stablecoin {
         self.raw_burn(&self.caller(), &amount);
     }
 
+    /// Burns tokens from the given address, consuming the allowance it granted to the calling minter.
+    pub fn burn_from(&mut self, owner: &Address, amount: U256) {
+        self.assert_burn_and_mint_enabled();
+        let spender = self.caller();
+        self.require_not_blacklisted(&spender);
+        self.require_not_blacklisted(owner);
+        self.require_role(&spender, &Roles::Minter);
+        if *owner == spender {
+            self.env().revert(Error::InvalidBurnTarget)
+        }
+        if amount == U256::zero() {
+            self.env().revert(Error::InvalidAmount)
+        }
+        let allowance = self.allowance(owner, &spender);
+        self.allowances.set(
+            owner,
+            &spender,
+            allowance
+                .checked_sub(amount)
+                .unwrap_or_revert_with(&self.env(), Error::InsufficientAllowance),
+        );
+        self.env().emit_event(DecreaseAllowance {
+            owner: *owner,
+            spender,
+            allowance,
+            decr_by: amount,
+        });
+        self.raw_burn(owner, &amount);
+    }
+
+    /// Moves tokens of the caller into escrow and records a redemption request, returns its id.
+    pub fn request_redemption(&mut self, amount: U256, reference: String) -> u32 {
+        self.assert_burn_and_mint_enabled();
+        let holder = self.caller();
+        self.require_not_blacklisted(&holder);
+        if amount == U256::zero() {
+            self.env().revert(Error::InvalidAmount)
+        }
+        self.raw_transfer(&holder, &self.env().self_address(), &amount);
+        self.redemptions.create(&holder, amount, reference)
+    }
+
+    /// Burns the escrowed tokens of a pending redemption, can only be called by a minter.
+    pub fn finalize_redemption(&mut self, id: u32) {
+        let minter = self.caller();
+        self.require_role(&minter, &Roles::Minter);
+        self.require_not_blacklisted(&minter);
+        let redemption = self.redemptions.get_pending(id);
+        self.redemptions.finalize(id, &minter);
+        self.raw_burn(&self.env().self_address(), &redemption.amount);
+    }
+
+    /// Refunds the escrowed tokens of a pending redemption, can only be called by a minter.
+    pub fn reject_redemption(&mut self, id: u32) {
+        let minter = self.caller();
+        self.require_role(&minter, &Roles::Minter);
+        self.require_not_blacklisted(&minter);
+        let redemption = self.redemptions.get_pending(id);
+        self.redemptions.reject(id, &minter);
+        self.raw_transfer(
+            &self.env().self_address(),
+            &redemption.holder,
+            &redemption.amount,
+        );
+    }
+
     /// Mints new tokens and assigns them to the given address.
     pub fn mint(&mut self, owner: &Address, amount: U256) {
         self.require_role(&self.caller(), &Roles::Minter);
-        self.require_not_role(owner, &Roles::Blacklisted);
+        self.require_not_blacklisted(owner);
         self.assert_burn_and_mint_enabled();
         let minter_allowance: U256 = self.minter_allowances.get_or_default(&self.env().caller());
         if &minter_allowance < &amount {
             self.env().revert(Error::InsufficientMinterAllowance);
         }
-        self.minter_allowances.subtract(&self.caller(), amount);
+        let minter = self.caller();
+        self.minter_allowances.subtract(&minter, amount);
+        self.controllers.consume_window(&minter, amount);
         self.raw_mint(owner, &amount);
     }
 
     /// Pause this contract
     pub fn pause(&mut self) {
         self.require_role(&self.caller(), &Roles::Pauser);
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
-        self.paused.set(true);
-        self.env().emit_event(Paused {});
+        self.require_not_blacklisted(&self.caller());
+        self.raw_pause();
     }
 
     /// Unpause this contract
     pub fn unpause(&mut self) {
         self.require_role(&self.caller(), &Roles::Pauser);
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
-        self.paused.set(false);
-        self.env().emit_event(Unpaused {});
+        self.require_not_blacklisted(&self.caller());
+        self.schedule_or_execute(AdminAction::Unpause);
+    }
+
+    /// Pause a single operation
+    pub fn pause_operation(&mut self, operation: PausableOperation) {
+        self.require_role(&self.caller(), &Roles::Pauser);
+        self.require_not_blacklisted(&self.caller());
+        self.raw_set_operation_paused(operation, true);
+    }
+
+    /// Unpause a single operation
+    pub fn unpause_operation(&mut self, operation: PausableOperation) {
+        self.require_role(&self.caller(), &Roles::Pauser);
+        self.require_not_blacklisted(&self.caller());
+        self.schedule_or_execute(AdminAction::UnpauseOperation { operation });
     }
 
     /// Blacklist an account
     pub fn blacklist(&mut self, account: &Address) {
         self.require_role(&self.caller(), &Roles::Blacklister);
-        self.roles.configure_role(&Roles::Blacklisted, account);
-        self.env().emit_event(Blacklist { account: *account });
+        self.raw_blacklist(account, BlacklistEntry::default());
+    }
+
+    /// Blacklist an account, recording why and optionally until which block time the restriction applies
+    pub fn blacklist_with_reason(
+        &mut self,
+        account: &Address,
+        reason_code: u32,
+        reference: String,
+        expires_at: Option<u64>,
+    ) {
+        self.require_role(&self.caller(), &Roles::Blacklister);
+        self.raw_blacklist(
+            account,
+            BlacklistEntry {
+                reason_code,
+                reference,
+                expires_at,
+            },
+        );
     }
 
     /// Remove an account from the Blacklist
     pub fn unblacklist(&mut self, account: &Address) {
         self.require_role(&self.caller(), &Roles::Blacklister);
-        self.roles.revoke_role(&Roles::Blacklisted, account);
-        self.env().emit_event(Unblacklist { account: *account });
+        self.raw_unblacklist(account);
+    }
+
+    /// Add several accounts to the Blacklist at once, returns the number of newly blacklisted accounts.
+    /// Accounts that are already blacklisted are skipped.
+    pub fn blacklist_many(&mut self, accounts: Vec<Address>) -> u32 {
+        self.require_role(&self.caller(), &Roles::Blacklister);
+        let mut count = 0;
+        for account in accounts {
+            if !self.roles.is_blacklisted(&account) {
+                self.raw_blacklist(&account, BlacklistEntry::default());
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Remove several accounts from the Blacklist at once, returns the number of unblacklisted accounts.
+    /// Accounts that are not on the Blacklist are skipped, lapsed entries are removed.
+    pub fn unblacklist_many(&mut self, accounts: Vec<Address>) -> u32 {
+        self.require_role(&self.caller(), &Roles::Blacklister);
+        let mut count = 0;
+        for account in accounts {
+            if self.roles.has_role(&Roles::Blacklisted, &account) {
+                self.raw_unblacklist(&account);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Freeze part of the balance of an account, can only be called by Blacklister.
+    /// The frozen amount may exceed the current balance, incoming tokens then stay frozen.
+    pub fn freeze(&mut self, account: &Address, amount: U256) {
+        self.require_role(&self.caller(), &Roles::Blacklister);
+        let frozen_balance = self
+            .balances
+            .get_frozen_or_default(account)
+            .checked_add(amount)
+            .unwrap_or_revert_with(&self.env(), Error::Overflow);
+        self.balances.set_frozen(account, frozen_balance);
+        self.env().emit_event(Frozen {
+            account: *account,
+            amount,
+            frozen_balance,
+        });
     }
 
-    /// Update the Blacklister, can only be called by Owner
+    /// Release part of the frozen balance of an account, can only be called by Blacklister
+    pub fn unfreeze(&mut self, account: &Address, amount: U256) {
+        self.require_role(&self.caller(), &Roles::Blacklister);
+        let frozen_balance = self
+            .balances
+            .get_frozen_or_default(account)
+            .checked_sub(amount)
+            .unwrap_or_revert_with(&self.env(), Error::InvalidAmount);
+        self.balances.set_frozen(account, frozen_balance);
+        self.env().emit_event(Unfrozen {
+            account: *account,
+            amount,
+            frozen_balance,
+        });
+    }
+
+    /// Burn the full balance of a blacklisted account, or move it to `recovery` when given.
+    /// Can only be called by Blacklister or Owner.
+    pub fn seize(&mut self, account: &Address, recovery: Option<Address>) {
+        let caller = self.caller();
+        if !self.roles.is_blacklister(&caller) {
+            self.require_owner();
+        }
+        self.require_not_blacklisted(&caller);
+        self.raw_seize(account, recovery);
+    }
+
+    /// Nominate a new Blacklister, can only be called by Owner.
+    /// The role is handed over once the nominee calls `accept_role`.
     pub fn update_blacklister(&mut self, new_blacklister: &Address) {
-        self.require_role(&self.caller(), &Roles::Owner);
-        self.roles.revoke_role(
-            &Roles::Blacklister,
-            &self
-                .blacklister
-                .get()
-                // borrow checker is unhappy if we unwrap_or_revert() here.
-                .unwrap(),
-        );
-        self.roles
-            .configure_role(&Roles::Blacklister, new_blacklister);
-        self.env().emit_event(BlacklisterChanged {
-            new_blacklister: *new_blacklister,
+        self.require_owner();
+        self.raw_update_blacklister(new_blacklister);
+    }
+
+    /// Add an Owner, can only be called by Owner
+    pub fn add_owner(&mut self, new_owner: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::AddOwner {
+            account: *new_owner,
         });
-        self.env().emit_event(BlacklisterChanged {
-            new_blacklister: *new_blacklister,
+    }
+
+    /// Remove an Owner, can only be called by Owner.
+    /// The last remaining Owner can never be removed.
+    pub fn remove_owner(&mut self, owner: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::RemoveOwner { account: *owner });
+    }
+
+    /// Nominate a replacement for a MasterMinter, can only be called by Owner.
+    /// The role is handed over once the nominee calls `accept_role`.
+    pub fn update_master_minter(
+        &mut self,
+        old_master_minter: &Address,
+        new_master_minter: &Address,
+    ) {
+        self.require_owner();
+        self.raw_update_master_minter(old_master_minter, new_master_minter);
+    }
+
+    /// Nominate a replacement for a Pauser, can only be called by Owner.
+    /// The role is handed over once the nominee calls `accept_role`.
+    pub fn update_pauser(&mut self, old_pauser: &Address, new_pauser: &Address) {
+        self.require_owner();
+        self.raw_update_pauser(old_pauser, new_pauser);
+    }
+
+    /// Nominate an account to take over one of the caller's privileged roles
+    /// (Owner, MasterMinter, Blacklister or Pauser).
+    /// The role is handed over once the nominee calls `accept_role`.
+    pub fn transfer_role(&mut self, role: Role, nominee: &Address) {
+        let caller = self.caller();
+        self.require_role(&caller, &role);
+        self.require_not_blacklisted(&caller);
+        if caller == *nominee {
+            self.env().revert(Error::CannotTargetSelfUser);
+        }
+        self.propose_role_transfer(&role, &caller, nominee);
+    }
+
+    /// Accept a pending role handover, must be called by the nominee.
+    /// The nomination lapses once it expires or its proposer loses the right to make it.
+    pub fn accept_role(&mut self, role: Role) {
+        let nominee = self.caller();
+        self.require_not_blacklisted(&nominee);
+        let pending = self
+            .roles
+            .pending_transfer(&role, &nominee)
+            .unwrap_or_revert_with(&self.env(), Error::MissingRoleTransfer);
+        if !self.is_role_transfer_live(&pending) {
+            self.env().revert(Error::RoleTransferExpired);
+        }
+        self.roles.set_pending_transfer(&role, &nominee, None);
+        self.schedule_or_execute(AdminAction::HandOverRole {
+            role,
+            previous_holder: pending.previous_holder,
+            new_holder: nominee,
         });
     }
 
-    /// Configure minter allowance
-    pub fn configure_minter_allowance(&mut self, minter_allowance: U256) {
-        self.require_role(&self.caller(), &Roles::Controller);
-        let minter = self.get_associated_minter(&self.caller());
-        self.require_not_role(&minter, &Roles::Blacklisted);
-        self.minter_allowances.set(&minter, minter_allowance);
-        self.env().emit_event(MinterConfigured {
-            minter,
-            minter_allowance,
+    /// Cancel a pending role handover, can be called by its proposer or by Owner
+    pub fn cancel_role_transfer(&mut self, role: Role, nominee: &Address) {
+        let pending = self
+            .roles
+            .pending_transfer(&role, nominee)
+            .unwrap_or_revert_with(&self.env(), Error::MissingRoleTransfer);
+        let caller = self.caller();
+        if pending.proposer != caller && !self.roles.has_role(&Roles::Owner, &caller) {
+            self.env().revert(Error::InsufficientRights);
+        }
+        self.roles.set_pending_transfer(&role, nominee, None);
+        self.env().emit_event(RoleTransferCancelled {
+            role,
+            proposer: pending.proposer,
+            nominee: *nominee,
         });
     }
 
-    /// Increase allowance for a minter
-    pub fn increase_minter_allowance(&mut self, increment: U256) {
-        self.require_role(&self.caller(), &Roles::Controller);
-        let minter = self.get_associated_minter(&self.caller());
-        self.require_not_role(&minter, &Roles::Blacklisted);
-        self.minter_allowances.add(&minter, increment);
-        self.env().emit_event(MinterConfigured {
-            minter,
-            minter_allowance: self.minter_allowance(&minter),
+    /// Propose an admin action, can only be called by Owner.
+    /// The proposer's approval is counted, and the action is carried out
+    /// as soon as the number of approving Owners reaches the threshold.
+    /// Once the threshold exceeds one, Owner actions can only be carried out through proposals.
+    pub fn propose(&mut self, action: AdminAction, lifetime: u64) -> u32 {
+        let proposer = self.caller();
+        self.require_role(&proposer, &Roles::Owner);
+        self.require_not_blacklisted(&proposer);
+        // handovers only follow the acceptance of a nominee
+        if let AdminAction::HandOverRole { .. } = action {
+            self.env().revert(Error::InvalidAction);
+        }
+        let expires_at = self.env().get_block_time().saturating_add(lifetime);
+        let id = self.proposals.create(action, &proposer, expires_at);
+        self.approve_and_try_execute(id, &proposer);
+        id
+    }
+
+    /// Approve a pending proposal, can only be called by Owner
+    pub fn approve_proposal(&mut self, id: u32) {
+        let approver = self.caller();
+        self.require_role(&approver, &Roles::Owner);
+        self.require_not_blacklisted(&approver);
+        self.approve_and_try_execute(id, &approver);
+    }
+
+    /// Cancel a pending proposal, can only be called by its proposer
+    pub fn cancel_proposal(&mut self, id: u32) {
+        let proposal = self.proposals.get_pending(id);
+        if proposal.proposer != self.caller() {
+            self.env().revert(Error::InsufficientRights);
+        }
+        self.proposals.cancel(id);
+    }
+
+    /// Grant a custom role, can only be called by a holder of the role's admin role.
+    /// The built-in roles are managed by their dedicated entrypoints.
+    pub fn grant_role(&mut self, role: Role, account: &Address) {
+        self.require_role_admin(&role);
+        self.schedule_or_execute(AdminAction::GrantRole {
+            role,
+            account: *account,
         });
     }
 
-    /// Decrease allowance for a minter
-    pub fn decrease_minter_allowance(&mut self, decrement: U256) {
-        self.require_role(&self.caller(), &Roles::Controller);
-        let minter = self.get_associated_minter(&self.caller());
-        self.require_not_role(&minter, &Roles::Blacklisted);
-        self.minter_allowances.subtract(&minter, decrement);
-        self.env().emit_event(MinterConfigured {
-            minter,
-            minter_allowance: self.minter_allowance(&minter),
+    /// Revoke a custom role, can only be called by a holder of the role's admin role
+    pub fn revoke_role(&mut self, role: Role, account: &Address) {
+        self.require_role_admin(&role);
+        self.schedule_or_execute(AdminAction::RevokeRole {
+            role,
+            account: *account,
         });
     }
 
+    /// Give up a custom role held by the caller
+    pub fn renounce_role(&mut self, role: Role) {
+        self.require_custom_role(&role);
+        let caller = self.caller();
+        self.roles.revoke_role(&role, &caller);
+    }
+
+    /// Change the admin role of a custom role, can only be called by a holder of the current admin role
+    pub fn set_role_admin(&mut self, role: Role, admin_role: Role) {
+        self.require_role_admin(&role);
+        self.schedule_or_execute(AdminAction::SetRoleAdmin { role, admin_role });
+    }
+
+    /// Add a Guardian, can only be called by Owner
+    pub fn add_guardian(&mut self, guardian: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::AddGuardian { account: *guardian });
+    }
+
+    /// Remove a Guardian, can only be called by Owner
+    pub fn remove_guardian(&mut self, guardian: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::RemoveGuardian { account: *guardian });
+    }
+
+    /// Add an Allowlister, can only be called by Owner
+    pub fn add_allowlister(&mut self, allowlister: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::AddAllowlister {
+            account: *allowlister,
+        });
+    }
+
+    /// Remove an Allowlister, can only be called by Owner
+    pub fn remove_allowlister(&mut self, allowlister: &Address) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::RemoveAllowlister {
+            account: *allowlister,
+        });
+    }
+
+    /// Move tokens held by the contract itself to the recipient, can only be called by Rescuer.
+    /// Rescuing this token leaves the redemption escrow untouched.
+    pub fn rescue_tokens(&mut self, token_contract: &Address, recipient: &Address, amount: U256) {
+        let caller = self.caller();
+        self.require_role(&caller, &Roles::Rescuer);
+        self.require_not_blacklisted(&caller);
+        self.require_not_blacklisted(recipient);
+        let self_address = self.env().self_address();
+        if *token_contract == self_address {
+            let rescuable = self
+                .balances
+                .available(&self_address)
+                .saturating_sub(self.redemptions.escrowed());
+            if amount > rescuable {
+                self.env().revert(Error::InsufficientRescuableBalance);
+            }
+            self.raw_transfer(&self_address, recipient, &amount);
+        } else {
+            Cep18TokenContractRef::new(self.env(), *token_contract).transfer(recipient, &amount);
+        }
+        self.env().emit_event(TokensRescued {
+            token_contract: *token_contract,
+            recipient: *recipient,
+            amount,
+        });
+    }
+
+    /// Add an account to the allowlist, can only be called by Allowlister
+    pub fn allowlist(&mut self, account: &Address) {
+        self.require_role(&self.caller(), &Roles::Allowlister);
+        self.require_not_blacklisted(&self.caller());
+        self.roles.configure_role(&Roles::Allowlisted, account);
+        self.env().emit_event(Allowlisted { account: *account });
+    }
+
+    /// Remove an account from the allowlist, can only be called by Allowlister
+    pub fn unallowlist(&mut self, account: &Address) {
+        self.require_role(&self.caller(), &Roles::Allowlister);
+        self.require_not_blacklisted(&self.caller());
+        self.roles.revoke_role(&Roles::Allowlisted, account);
+        self.env().emit_event(Unallowlisted { account: *account });
+    }
+
+    /// Configure the timelock, can only be called by Owner.
+    /// Sensitive changes are queued for `delay` milliseconds, a delay of zero disables the timelock.
+    /// Minter allowances are only delayed when they exceed `allowance_threshold`.
+    pub fn configure_timelock(&mut self, delay: u64, allowance_threshold: U256) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::ConfigureTimelock {
+            delay,
+            allowance_threshold,
+        });
+    }
+
+    /// Set the maximum supply of the token, can only be called by Owner
+    pub fn set_max_supply(&mut self, max_supply: U256) {
+        self.require_owner();
+        self.schedule_or_execute(AdminAction::SetMaxSupply { max_supply });
+    }
+
+    /// Carry out a queued action once its delay has passed
+    pub fn execute_queued_action(&mut self, id: u32) {
+        let queued = self.timelock.get_queued(id);
+        if self.env().get_block_time() < queued.ready_at {
+            self.env().revert(Error::TimelockNotElapsed);
+        }
+        if let (Some(controller), Some(minter)) =
+            (queued.controller, queued.action.allowance_minter())
+        {
+            if self.controllers.controller_of(&minter) != Some(controller) {
+                self.env().revert(Error::MinterNotControlled);
+            }
+        }
+        self.timelock.mark_executed(id);
+        self.execute_action(queued.action);
+    }
+
+    /// Cancel a queued action, can only be called by Guardian.
+    /// A Guardian cannot cancel its own removal.
+    pub fn cancel_queued_action(&mut self, id: u32) {
+        let caller = self.caller();
+        self.require_role(&caller, &Roles::Guardian);
+        self.require_not_blacklisted(&caller);
+        if let AdminAction::RemoveGuardian { account } = self.timelock.get_queued(id).action {
+            if account == caller {
+                self.env().revert(Error::InsufficientRights);
+            }
+        }
+        self.timelock.cancel(id);
+    }
+
+    /// Configure allowance for a minter of the calling controller
+    pub fn configure_minter_allowance(&mut self, minter: &Address, minter_allowance: U256) {
+        self.require_controller_of(minter);
+        self.require_not_blacklisted(minter);
+        self.schedule_or_execute(AdminAction::ConfigureMinterAllowance {
+            minter: *minter,
+            minter_allowance,
+        });
+    }
+
+    /// Increase allowance for a minter of the calling controller
+    pub fn increase_minter_allowance(&mut self, minter: &Address, increment: U256) {
+        self.require_controller_of(minter);
+        self.require_not_blacklisted(minter);
+        self.schedule_or_execute(AdminAction::IncreaseMinterAllowance {
+            minter: *minter,
+            increment,
+        });
+    }
+
+    /// Decrease allowance for a minter of the calling controller
+    pub fn decrease_minter_allowance(&mut self, minter: &Address, decrement: U256) {
+        self.require_controller_of(minter);
+        self.require_not_blacklisted(minter);
+        self.minter_allowances.subtract(minter, decrement);
+        self.emit_minter_configured(minter);
+    }
+
+    /// Limit the amount a minter of the calling controller can mint to a rolling window of `window`
+    /// milliseconds. At most `limit` can be minted at once, and the capacity refills continuously
+    /// at `limit / window`, so there is no window boundary at which the full limit frees up.
+    /// A window of zero removes the limit.
+    pub fn configure_minter_rate_limit(&mut self, minter: &Address, window: u64, limit: U256) {
+        self.require_controller_of(minter);
+        self.require_not_blacklisted(&self.caller());
+        self.controllers.configure_window(minter, window, limit);
+        self.emit_minter_configured(minter);
+    }
+
     /// Add a controller, minter pair
     pub fn configure_controller(&mut self, controller: &Address, minter: &Address) {
         self.require_role(&self.caller(), &Roles::MasterMinter);
-        self.require_not_role(controller, &Roles::Blacklisted);
-        self.require_not_role(minter, &Roles::Blacklisted);
-        self.roles.configure_role(&Roles::Controller, controller);
-        self.roles.configure_role(&Roles::Minter, minter);
-        self.controllers.set(&controller, *minter);
-        self.env().emit_event(ControllerConfigured {
+        self.schedule_or_execute(AdminAction::ConfigureController {
             controller: *controller,
             minter: *minter,
         });
     }
 
-    /// Remove a controller
-    pub fn remove_controller(&mut self, controller: &Address) {
+    /// Remove a controller and unpair its minters.
+    /// With `remove_minters` the minters also lose their role and their remaining allowance.
+    pub fn remove_controller(&mut self, controller: &Address, remove_minters: bool) {
         self.require_role(&self.caller(), &Roles::MasterMinter);
-        self.roles.revoke_role(&Roles::Controller, controller);
-        self.env().emit_event(ControllerRemoved {
-            controller: *controller,
-        });
+        self.raw_remove_controller(controller, remove_minters);
     }
 
-    /// Remove the minter role from an account
-    pub fn remove_minter(&mut self) {
-        self.require_role(&self.caller(), &Roles::Controller);
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
-        let minter: Address = self.get_associated_minter(&self.env().caller());
-        self.roles.revoke_role(&Roles::Minter, &minter);
-        self.env().emit_event(MinterRemoved { minter })
+    /// Remove a minter of the calling controller, which unpairs it and drops its remaining allowance
+    pub fn remove_minter(&mut self, minter: &Address) {
+        self.require_controller_of(minter);
+        self.require_not_blacklisted(&self.caller());
+        self.controllers.unpair(minter);
+        self.roles.revoke_role(&Roles::Minter, minter);
+        self.minter_allowances.set(minter, U256::zero());
+        self.controllers.configure_window(minter, 0, U256::zero());
+        self.env().emit_event(MinterRemoved { minter: *minter })
     }
 
     // Queries start here
@@ -361,11 +1038,16 @@ impl Stablecoin {
         self.roles.is_minter(account)
     }
 
-    /// Query if an account is blacklisted
+    /// Query if an account is blacklisted, entries lapse once their expiry has passed
     pub fn is_blacklisted(&self, account: &Address) -> bool {
         self.roles.is_blacklisted(account)
     }
 
+    /// Query the blacklist entry of an account, including lapsed entries
+    pub fn blacklist_entry(&self, account: &Address) -> Option<BlacklistEntry> {
+        self.roles.blacklist_entry(account)
+    }
+
     /// Query the owners of this account
     pub fn is_owner(&self, account: &Address) -> bool {
         self.roles.is_owner(account)
@@ -376,11 +1058,71 @@ impl Stablecoin {
         self.roles.is_pauser(account)
     }
 
-    /// Query a minter for a controller
-    pub fn get_minter(&mut self) -> Address {
-        self.require_role(&self.caller(), &Roles::Controller);
-        self.require_not_role(&self.caller(), &Roles::Blacklisted);
-        self.get_associated_minter(&self.caller())
+    /// Query if a transfer authorization was used or cancelled
+    pub fn authorization_state(&self, authorizer: &Address, nonce: [u8; 32]) -> bool {
+        self.signatures.authorization_state(authorizer, &nonce)
+    }
+
+    /// Query the nonce the next permit of an owner must use
+    pub fn nonce(&self, owner: &Address) -> u64 {
+        self.signatures.nonce(owner)
+    }
+
+    /// Query which operations are paused
+    pub fn pause_state(&self) -> PauseState {
+        self.paused.get_or_default()
+    }
+
+    /// Query the frozen portion of the balance of an account
+    pub fn frozen_balance_of(&self, account: &Address) -> U256 {
+        self.balances.get_frozen_or_default(account)
+    }
+
+    /// Query the balance of an account that is not frozen
+    pub fn available_balance_of(&self, account: &Address) -> U256 {
+        self.balances.available(account)
+    }
+
+    /// Query if an account is allowlisted
+    pub fn is_allowlisted(&self, account: &Address) -> bool {
+        self.roles.is_allowlisted(account)
+    }
+
+    /// Query if an account is a master minter
+    pub fn is_master_minter(&self, account: &Address) -> bool {
+        self.roles.is_master_minter(account)
+    }
+
+    /// Query the minters managed by a controller
+    pub fn minters_of(&self, controller: &Address) -> Vec<Address> {
+        self.controllers.minters_of(controller)
+    }
+
+    /// Query the controller managing a minter
+    pub fn controller_of(&self, minter: &Address) -> Option<Address> {
+        self.controllers.controller_of(minter)
+    }
+
+    /// Query if an account holds a role
+    pub fn has_role(&self, role: Role, account: &Address) -> bool {
+        self.roles.has_role(&role, account)
+    }
+
+    /// Query the role whose holders can grant and revoke a role
+    pub fn role_admin(&self, role: Role) -> Role {
+        self.roles.role_admin(&role)
+    }
+
+    /// Query the number of accounts holding a role.
+    /// Blacklisted accounts whose entry has lapsed are counted until they are unblacklisted.
+    pub fn role_member_count(&self, role: Role) -> u32 {
+        self.roles.role_member_count(&role)
+    }
+
+    /// Query the accounts holding a role, `limit` accounts starting at position `offset`.
+    /// Blacklisted accounts whose entry has lapsed are listed until they are unblacklisted.
+    pub fn role_members(&self, role: Role, offset: u32, limit: u32) -> Vec<Address> {
+        self.roles.role_members(&role, offset, limit)
     }
 
     /// Query the allowance of a minter
@@ -388,8 +1130,57 @@ impl Stablecoin {
         self.minter_allowances.get_or_default(minter)
     }
 
-    fn require_unpaused(&self) {
-        if self.paused.get().unwrap_or(false) {
+    /// Query the mint rate limit of a minter and its usage in the current window
+    pub fn mint_window(&self, minter: &Address) -> Option<MintWindow> {
+        self.controllers.window(minter)
+    }
+
+    /// Query a redemption request
+    pub fn redemption(&self, id: u32) -> Option<Redemption> {
+        self.redemptions.get(id)
+    }
+
+    /// Query the amount held in escrow by pending redemptions
+    pub fn escrowed_redemptions(&self) -> U256 {
+        self.redemptions.escrowed()
+    }
+
+    /// Query the maximum supply of the token
+    pub fn max_supply(&self) -> U256 {
+        self.total_supply.max()
+    }
+
+    /// Query a proposal
+    pub fn proposal(&self, id: u32) -> Option<Proposal> {
+        self.proposals.get(id)
+    }
+
+    /// Query the number of Owner approvals a proposal needs to be carried out
+    pub fn proposal_threshold(&self) -> u32 {
+        self.proposals.threshold()
+    }
+
+    /// Query an action queued by the timelock
+    pub fn queued_action(&self, id: u32) -> Option<QueuedAction> {
+        self.timelock.get(id)
+    }
+
+    /// Query the timelock delay in milliseconds
+    pub fn timelock_delay(&self) -> u64 {
+        self.timelock.delay()
+    }
+
+    /// Query the pending handover of a role to a nominee
+    pub fn pending_role_transfer(
+        &self,
+        role: Role,
+        nominee: &Address,
+    ) -> Option<PendingRoleTransfer> {
+        self.roles.pending_transfer(&role, nominee)
+    }
+
+    fn require_unpaused(&self, operation: PausableOperation) {
+        if self.paused.get_or_default().is_paused(&operation) {
             self.env().revert(Error::ContractIsPaused);
         }
     }
@@ -400,17 +1191,123 @@ impl Stablecoin {
         }
     }
 
-    fn require_not_role(&mut self, account: &Address, role: &Role) {
-        if self.roles.has_role(&role, account) {
+    fn require_not_blacklisted(&mut self, account: &Address) {
+        if self.roles.is_blacklisted(account) {
             self.env().revert(Error::InsufficientRights)
         }
     }
 
-    // Get the minter that is associated with the controller
-    fn get_associated_minter(&mut self, controller: &Address) -> Address {
-        self.controllers
-            .get(&controller)
-            .unwrap_or_revert_with(&self.env(), Error::MissingController)
+    fn require_signature(&mut self, message: &Bytes, signature: &Bytes, public_key: &PublicKey) {
+        if !self.env().verify_signature(message, signature, public_key) {
+            self.env().revert(Error::InvalidSignature);
+        }
+    }
+
+    // Checks the validity window and signature of a transfer authorization and marks it as used
+    fn use_transfer_authorization(
+        &mut self,
+        public_key: &PublicKey,
+        message: &Bytes,
+        signature: &Bytes,
+        valid_after: u64,
+        valid_before: u64,
+        nonce: &[u8; 32],
+    ) {
+        let now = self.env().get_block_time();
+        if now <= valid_after {
+            self.env().revert(Error::AuthorizationNotYetValid);
+        }
+        if now >= valid_before {
+            self.env().revert(Error::AuthorizationExpired);
+        }
+        self.require_signature(message, signature, public_key);
+        let authorizer = Address::Account(public_key.to_account_hash());
+        self.signatures.use_authorization(&authorizer, nonce);
+        self.env().emit_event(AuthorizationUsed {
+            authorizer,
+            nonce: *nonce,
+        });
+    }
+
+    fn require_memo_length(&self, memo: &str) {
+        if memo.len() > MAX_MEMO_LENGTH {
+            self.env().revert(Error::MemoTooLong);
+        }
+    }
+
+    // Checks the recipients of a batch sent from `sender` and returns its total amount
+    fn batch_total(&mut self, sender: &Address, transfers: &[(Address, U256)]) -> U256 {
+        let mut total = U256::zero();
+        for (recipient, amount) in transfers.iter() {
+            if recipient == sender {
+                self.env().revert(Error::CannotTargetSelfUser);
+            }
+            self.require_not_blacklisted(recipient);
+            total = total
+                .checked_add(*amount)
+                .unwrap_or_revert_with(&self.env(), Error::Overflow);
+        }
+        total
+    }
+
+    fn transfer_authorized(&mut self, from: &Address, to: &Address, value: &U256) {
+        self.require_not_blacklisted(from);
+        self.require_not_blacklisted(to);
+        if from == to {
+            self.env().revert(Error::CannotTargetSelfUser);
+        }
+        self.raw_transfer(from, to, value);
+    }
+
+    // Under the Allowlist modality only allowlisted accounts and the contract itself can hold tokens
+    fn require_allowlisted(&mut self, account: &Address) {
+        if self.modality.get_or_default().allowlist_enabled()
+            && *account != self.env().self_address()
+            && !self.roles.is_allowlisted(account)
+        {
+            self.env().revert(Error::NotAllowlisted);
+        }
+    }
+
+    // Owner entrypoints act on a single approval, so they are closed once the threshold exceeds one
+    fn require_owner(&mut self) {
+        self.require_role(&self.caller(), &Roles::Owner);
+        if self.proposals.threshold() > 1 {
+            self.env().revert(Error::ProposalRequired);
+        }
+    }
+
+    fn require_custom_role(&mut self, role: &Role) {
+        if Roles::is_reserved(role) {
+            self.env().revert(Error::ReservedRole);
+        }
+    }
+
+    // The caller must hold the admin role of a custom role
+    fn require_role_admin(&mut self, role: &Role) {
+        self.require_custom_role(role);
+        let admin_role = self.roles.role_admin(role);
+        if admin_role == Roles::Owner {
+            self.require_owner();
+        } else {
+            self.require_role(&self.caller(), &admin_role);
+        }
+        self.require_not_blacklisted(&self.caller());
+    }
+
+    // Unlike require_role this checks a target account rather than the caller
+    fn require_holds_role(&mut self, account: &Address, role: &Role) {
+        if !self.roles.has_role(role, account) {
+            self.env().revert(Error::MissingRole);
+        }
+    }
+
+    // The caller must be the controller paired with the minter
+    fn require_controller_of(&mut self, minter: &Address) {
+        self.require_role(&self.caller(), &Roles::Controller);
+        if self.controllers.controller_of(minter) != Some(self.caller()) {
+            self.env().revert(Error::MinterNotControlled);
+        }
     }
 
     fn caller(&self) -> Address {
@@ -421,10 +1318,15 @@ impl Stablecoin {
 impl Stablecoin {
     /// Transfers tokens from the sender to the recipient without checking the permissions.
     fn raw_transfer(&mut self, sender: &Address, recipient: &Address, amount: &U256) {
-        self.require_unpaused();
+        self.require_unpaused(PausableOperation::Transfer);
+        self.require_allowlisted(sender);
+        self.require_allowlisted(recipient);
         if *amount > self.balances.get_or_default(sender) {
             self.env().revert(Error::InsufficientBalance)
         }
+        if *amount > self.balances.available(sender) {
+            self.env().revert(Error::InsufficientAvailableBalance)
+        }
 
         if amount > &U256::zero() {
             self.balances.subtract(sender, *amount);
@@ -440,7 +1342,11 @@ impl Stablecoin {
 
     /// Mints new tokens and assigns them to the given address without checking the permissions.
     fn raw_mint(&mut self, owner: &Address, amount: &U256) {
-        self.require_unpaused();
+        self.require_unpaused(PausableOperation::Mint);
+        self.require_allowlisted(owner);
+        if self.total_supply.get().saturating_add(*amount) > self.total_supply.max() {
+            self.env().revert(Error::SupplyCapExceeded);
+        }
         self.total_supply.add(*amount);
         self.balances.add(owner, *amount);
 
@@ -452,7 +1358,10 @@ impl Stablecoin {
 
     /// Burns the given amount of tokens from the given address without checking the permissions.
     fn raw_burn(&mut self, owner: &Address, amount: &U256) {
-        self.require_unpaused();
+        self.require_unpaused(PausableOperation::Burn);
+        if *amount > self.balances.available(owner) {
+            self.env().revert(Error::InsufficientAvailableBalance)
+        }
         self.total_supply.subtract(*amount);
         self.balances.subtract(owner, *amount);
 
@@ -462,6 +1371,407 @@ impl Stablecoin {
         });
     }
 
+    fn raw_pause(&mut self) {
+        self.paused.set(PauseState::all(true));
+        self.env().emit_event(Paused {});
+    }
+
+    fn raw_unpause(&mut self) {
+        self.paused.set(PauseState::all(false));
+        self.env().emit_event(Unpaused {});
+    }
+
+    fn raw_set_operation_paused(&mut self, operation: PausableOperation, paused: bool) {
+        let mut state = self.paused.get_or_default();
+        state.set(&operation, paused);
+        self.paused.set(state);
+        if paused {
+            self.env().emit_event(OperationPaused { operation });
+        } else {
+            self.env().emit_event(OperationUnpaused { operation });
+        }
+    }
+
+    fn raw_blacklist(&mut self, account: &Address, entry: BlacklistEntry) {
+        self.roles.configure_role(&Roles::Blacklisted, account);
+        self.roles.set_blacklist_entry(account, Some(entry.clone()));
+        self.env().emit_event(Blacklist {
+            account: *account,
+            reason_code: entry.reason_code,
+            reference: entry.reference,
+            expires_at: entry.expires_at,
+        });
+    }
+
+    fn raw_seize(&mut self, account: &Address, recovery: Option<Address>) {
+        if !self.roles.is_blacklisted(account) {
+            self.env().revert(Error::AccountNotBlacklisted);
+        }
+        let amount = self.balances.get_or_default(account);
+        match recovery {
+            Some(recovery) => {
+                self.require_not_blacklisted(&recovery);
+                self.require_allowlisted(&recovery);
+                self.balances.subtract(account, amount);
+                self.balances.add(&recovery, amount);
+                self.env().emit_event(Transfer {
+                    sender: *account,
+                    recipient: recovery,
+                    amount,
+                });
+            }
+            None => {
+                self.total_supply.subtract(amount);
+                self.balances.subtract(account, amount);
+                self.env().emit_event(Burn {
+                    owner: *account,
+                    amount,
+                });
+            }
+        }
+        // the frozen part went with the seized balance, it must not catch later incoming tokens
+        let frozen_balance = self.balances.get_frozen_or_default(account);
+        if !frozen_balance.is_zero() {
+            self.balances.set_frozen(account, U256::zero());
+            self.env().emit_event(Unfrozen {
+                account: *account,
+                amount: frozen_balance,
+                frozen_balance: U256::zero(),
+            });
+        }
+        self.env().emit_event(Seized {
+            account: *account,
+            amount,
+            recovery,
+        });
+    }
+
+    fn raw_unblacklist(&mut self, account: &Address) {
+        self.roles.revoke_role(&Roles::Blacklisted, account);
+        self.roles.set_blacklist_entry(account, None);
+        self.env().emit_event(Unblacklist { account: *account });
+    }
+
+    fn raw_update_blacklister(&mut self, new_blacklister: &Address) {
+        let blacklister = self
+            .roles
+            .blacklister()
+            .unwrap_or_revert_with(&self.env(), Error::MissingBlacklister);
+        self.propose_role_transfer(&Roles::Blacklister, &blacklister, new_blacklister);
+    }
+
+    fn raw_update_master_minter(
+        &mut self,
+        old_master_minter: &Address,
+        new_master_minter: &Address,
+    ) {
+        self.require_holds_role(old_master_minter, &Roles::MasterMinter);
+        self.propose_role_transfer(&Roles::MasterMinter, old_master_minter, new_master_minter);
+    }
+
+    fn raw_update_pauser(&mut self, old_pauser: &Address, new_pauser: &Address) {
+        self.require_holds_role(old_pauser, &Roles::Pauser);
+        self.propose_role_transfer(&Roles::Pauser, old_pauser, new_pauser);
+    }
+
+    fn raw_add_owner(&mut self, new_owner: &Address) {
+        self.require_not_blacklisted(new_owner);
+        self.roles.configure_role(&Roles::Owner, new_owner);
+    }
+
+    fn raw_remove_owner(&mut self, owner: &Address) {
+        self.require_holds_role(owner, &Roles::Owner);
+        let owners = self.roles.role_member_count(&Roles::Owner);
+        if owners <= 1 {
+            self.env().revert(Error::CannotRemoveLastOwner);
+        }
+        // the remaining Owners must still be able to reach the threshold
+        if owners - 1 < self.proposals.threshold() {
+            self.env().revert(Error::InvalidThreshold);
+        }
+        self.roles.revoke_role(&Roles::Owner, owner);
+    }
+
+    fn raw_configure_controller(&mut self, controller: &Address, minter: &Address) {
+        self.require_not_blacklisted(controller);
+        self.require_not_blacklisted(minter);
+        if self.controllers.controller_of(minter).is_some() {
+            self.env().revert(Error::MinterAlreadyControlled);
+        }
+        self.roles.configure_role(&Roles::Controller, controller);
+        self.roles.configure_role(&Roles::Minter, minter);
+        self.controllers.pair(controller, minter);
+        self.env().emit_event(ControllerConfigured {
+            controller: *controller,
+            minter: *minter,
+        });
+    }
+
+    fn raw_remove_controller(&mut self, controller: &Address, remove_minters: bool) {
+        for minter in self.controllers.minters_of(controller) {
+            self.controllers.unpair(&minter);
+            // a kept minter loses what its controller granted until it is paired again
+            self.minter_allowances.set(&minter, U256::zero());
+            self.controllers.configure_window(&minter, 0, U256::zero());
+            if remove_minters {
+                self.roles.revoke_role(&Roles::Minter, &minter);
+                self.env().emit_event(MinterRemoved { minter });
+            }
+        }
+        self.roles.revoke_role(&Roles::Controller, controller);
+        self.env().emit_event(ControllerRemoved {
+            controller: *controller,
+        });
+    }
+
+    fn raw_hand_over_role(&mut self, role: &Role, previous_holder: &Address, new_holder: &Address) {
+        // handovers may be carried out after a delay, so the checks of `transfer_role` are repeated
+        if !Roles::is_transferable(role) {
+            self.env().revert(Error::InvalidRole);
+        }
+        // a superseded handover must not duplicate the role
+        self.require_holds_role(previous_holder, role);
+        self.require_not_blacklisted(new_holder);
+        self.roles.revoke_role(role, previous_holder);
+        self.roles.configure_role(role, new_holder);
+        if *role == Roles::Blacklister {
+            self.roles.set_blacklister(new_holder);
+            self.env().emit_event(BlacklisterChanged {
+                new_blacklister: *new_holder,
+            });
+        }
+        self.env().emit_event(RoleTransferAccepted {
+            role: *role,
+            previous_holder: *previous_holder,
+            new_holder: *new_holder,
+        });
+    }
+
+    fn raw_configure_minter_allowance(&mut self, minter: &Address, minter_allowance: U256) {
+        self.require_not_blacklisted(minter);
+        self.minter_allowances.set(minter, minter_allowance);
+        self.emit_minter_configured(minter);
+    }
+
+    fn raw_increase_minter_allowance(&mut self, minter: &Address, increment: U256) {
+        self.require_not_blacklisted(minter);
+        self.minter_allowances.add(minter, increment);
+        self.emit_minter_configured(minter);
+    }
+
+    fn emit_minter_configured(&mut self, minter: &Address) {
+        let window = self.controllers.window(minter);
+        self.env().emit_event(MinterConfigured {
+            minter: *minter,
+            minter_allowance: self.minter_allowance(minter),
+            window: window.as_ref().map_or(0, |window| window.duration),
+            window_limit: window.map_or(U256::zero(), |window| window.limit),
+        });
+    }
+
+    fn raw_set_max_supply(&mut self, max_supply: U256) {
+        if max_supply < self.total_supply.get() {
+            self.env().revert(Error::SupplyCapExceeded);
+        }
+        let previous_max_supply = self.total_supply.max();
+        self.total_supply.set_max(max_supply);
+        self.env().emit_event(MaxSupplyChanged {
+            previous_max_supply,
+            new_max_supply: max_supply,
+        });
+    }
+
+    fn raw_set_proposal_threshold(&mut self, threshold: u32) {
+        if threshold == 0 || threshold > self.roles.role_member_count(&Roles::Owner) {
+            self.env().revert(Error::InvalidThreshold);
+        }
+        self.proposals.set_threshold(threshold);
+    }
+
+    /// Records the approval and carries out the proposed action once the threshold is reached.
+    fn approve_and_try_execute(&mut self, id: u32, approver: &Address) {
+        // approvals of accounts that are no longer Owners do not count
+        let owners = self.roles.role_member_count(&Roles::Owner);
+        let approvals = self
+            .roles
+            .role_members(&Roles::Owner, 0, owners)
+            .iter()
+            .filter(|owner| *owner == approver || self.proposals.has_approved(id, owner))
+            .count() as u32;
+        self.proposals.approve(id, approver, approvals);
+        if approvals >= self.proposals.threshold() {
+            let proposal = self.proposals.get_pending(id);
+            self.proposals.mark_executed(id);
+            self.schedule_or_execute(proposal.action);
+        }
+    }
+
+    /// Queues the action if the timelock applies to it, otherwise carries it out right away.
+    fn schedule_or_execute(&mut self, action: AdminAction) {
+        let delay = self.timelock.delay();
+        let delayed = delay > 0
+            && match &action {
+                AdminAction::ConfigureMinterAllowance {
+                    minter_allowance, ..
+                } => *minter_allowance > self.timelock.allowance_threshold(),
+                AdminAction::IncreaseMinterAllowance { minter, increment } => {
+                    self.minter_allowance(minter).saturating_add(*increment)
+                        > self.timelock.allowance_threshold()
+                }
+                AdminAction::ConfigureController { .. }
+                | AdminAction::Unpause
+                | AdminAction::UnpauseOperation { .. }
+                | AdminAction::AddOwner { .. }
+                | AdminAction::RemoveOwner { .. }
+                | AdminAction::HandOverRole { .. }
+                | AdminAction::AddGuardian { .. }
+                | AdminAction::RemoveGuardian { .. }
+                | AdminAction::AddAllowlister { .. }
+                | AdminAction::RemoveAllowlister { .. }
+                | AdminAction::ConfigureTimelock { .. }
+                | AdminAction::SetMaxSupply { .. }
+                | AdminAction::GrantRole { .. }
+                | AdminAction::RevokeRole { .. }
+                | AdminAction::SetRoleAdmin { .. } => true,
+                _ => false,
+            };
+        if delayed {
+            let ready_at = self.env().get_block_time().saturating_add(delay);
+            // a minter allowance change requested by a controller stays bound to it
+            let controller = action
+                .allowance_minter()
+                .and_then(|minter| self.controllers.controller_of(&minter))
+                .filter(|controller| *controller == self.caller());
+            self.timelock.queue(action, ready_at, controller);
+        } else {
+            self.execute_action(action);
+        }
+    }
+
+    /// Carries out an admin action without checking the permissions.
+    fn execute_action(&mut self, action: AdminAction) {
+        match action {
+            AdminAction::ConfigureController { controller, minter } => {
+                self.raw_configure_controller(&controller, &minter)
+            }
+            AdminAction::RemoveController {
+                controller,
+                remove_minters,
+            } => self.raw_remove_controller(&controller, remove_minters),
+            AdminAction::Pause => self.raw_pause(),
+            AdminAction::Unpause => self.raw_unpause(),
+            AdminAction::UnpauseOperation { operation } => {
+                self.raw_set_operation_paused(operation, false)
+            }
+            AdminAction::Blacklist { account } => {
+                self.raw_blacklist(&account, BlacklistEntry::default())
+            }
+            AdminAction::Unblacklist { account } => self.raw_unblacklist(&account),
+            AdminAction::Seize { account, recovery } => self.raw_seize(&account, recovery),
+            AdminAction::UpdateBlacklister { new_blacklister } => {
+                self.raw_update_blacklister(&new_blacklister)
+            }
+            AdminAction::UpdateMasterMinter {
+                old_master_minter,
+                new_master_minter,
+            } => self.raw_update_master_minter(&old_master_minter, &new_master_minter),
+            AdminAction::UpdatePauser {
+                old_pauser,
+                new_pauser,
+            } => self.raw_update_pauser(&old_pauser, &new_pauser),
+            AdminAction::AddOwner { account } => self.raw_add_owner(&account),
+            AdminAction::RemoveOwner { account } => self.raw_remove_owner(&account),
+            AdminAction::SetProposalThreshold { threshold } => {
+                self.raw_set_proposal_threshold(threshold)
+            }
+            AdminAction::HandOverRole {
+                role,
+                previous_holder,
+                new_holder,
+            } => self.raw_hand_over_role(&role, &previous_holder, &new_holder),
+            AdminAction::ConfigureMinterAllowance {
+                minter,
+                minter_allowance,
+            } => self.raw_configure_minter_allowance(&minter, minter_allowance),
+            AdminAction::IncreaseMinterAllowance { minter, increment } => {
+                self.raw_increase_minter_allowance(&minter, increment)
+            }
+            AdminAction::AddGuardian { account } => {
+                self.roles.configure_role(&Roles::Guardian, &account)
+            }
+            AdminAction::RemoveGuardian { account } => {
+                self.roles.revoke_role(&Roles::Guardian, &account)
+            }
+            AdminAction::AddAllowlister { account } => {
+                self.require_not_blacklisted(&account);
+                self.roles.configure_role(&Roles::Allowlister, &account)
+            }
+            AdminAction::RemoveAllowlister { account } => {
+                self.roles.revoke_role(&Roles::Allowlister, &account)
+            }
+            AdminAction::ConfigureTimelock {
+                delay,
+                allowance_threshold,
+            } => self.timelock.configure(delay, allowance_threshold),
+            AdminAction::SetMaxSupply { max_supply } => self.raw_set_max_supply(max_supply),
+            // proposals reach these without the entrypoint checks, built-in roles stay off limits
+            AdminAction::GrantRole { role, account } => {
+                self.require_custom_role(&role);
+                self.require_not_blacklisted(&account);
+                self.roles.configure_role(&role, &account)
+            }
+            AdminAction::RevokeRole { role, account } => {
+                self.require_custom_role(&role);
+                self.roles.revoke_role(&role, &account)
+            }
+            AdminAction::SetRoleAdmin { role, admin_role } => {
+                self.require_custom_role(&role);
+                self.roles.set_role_admin(&role, &admin_role)
+            }
+        }
+    }
+
+    /// Records a handover of the role from the previous holder to the nominee.
+    fn propose_role_transfer(&mut self, role: &Role, previous_holder: &Address, nominee: &Address) {
+        if !Roles::is_transferable(role) {
+            self.env().revert(Error::InvalidRole);
+        }
+        self.require_not_blacklisted(nominee);
+        if let Some(pending) = self.roles.pending_transfer(role, nominee) {
+            if self.is_role_transfer_live(&pending) {
+                self.env().revert(Error::RoleTransferPending);
+            }
+        }
+        let proposer = self.caller();
+        let expires_at = self
+            .env()
+            .get_block_time()
+            .saturating_add(ROLE_TRANSFER_LIFETIME);
+        self.roles.set_pending_transfer(
+            role,
+            nominee,
+            Some(PendingRoleTransfer {
+                proposer,
+                previous_holder: *previous_holder,
+                expires_at,
+            }),
+        );
+        self.env().emit_event(RoleTransferProposed {
+            role: *role,
+            proposer,
+            previous_holder: *previous_holder,
+            nominee: *nominee,
+        });
+    }
+
+    // A nomination stands until it expires, and only while its proposer is still an Owner
+    // or the holder handing over their own role.
+    fn is_role_transfer_live(&self, pending: &PendingRoleTransfer) -> bool {
+        self.env().get_block_time() <= pending.expires_at
+            && (pending.proposer == pending.previous_holder
+                || self.roles.has_role(&Roles::Owner, &pending.proposer))
+    }
+
     fn assert_burn_and_mint_enabled(&mut self) {
         // check if mint_burn is enabled
         if !self.modality.get_or_default().mint_and_burn_enabled() {
@@ -479,6 +1789,7 @@ pub (crate) mod setup_tests {
     use odra::host::{Deployer, HostEnv, HostRef};
     use odra::Address;
 
+    use crate::stablecoin::utils::StablecoinModality;
     use crate::stablecoin::{StablecoinHostRef, StablecoinInitArgs};
 
     pub const TOKEN_NAME: &str = "USDCoin";
@@ -534,6 +1845,27 @@ pub (crate) mod setup_tests {
         StablecoinHostRef::deploy(env, args)
     }
 
+    /// Deploys with the given Owners and modality, and the default
+    /// master minter (1), blacklister (4) and pauser (5) accounts.
+    pub fn setup_with(
+        env: &HostEnv,
+        owners: Vec<Address>,
+        modality: StablecoinModality,
+    ) -> StablecoinHostRef {
+        let args = StablecoinInitArgs {
+            symbol: TOKEN_SYMBOL.to_string(),
+            name: TOKEN_NAME.to_string(),
+            decimals: TOKEN_DECIMALS,
+            initial_supply: TOKEN_TOTAL_SUPPLY.into(),
+            master_minter_list: vec![env.get_account(1)],
+            owner_list: owners,
+            pauser_list: vec![env.get_account(5)],
+            blacklister: env.get_account(4),
+            modality: Some(modality),
+        };
+        setup_with_args(env, args)
+    }
+
     pub fn invert_address(address: Address) -> Address {
         match address {
             Address::Account(hash) => Address::Contract(ContractPackageHash::new(hash.value())),
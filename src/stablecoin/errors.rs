@@ -51,6 +51,74 @@ pub enum Error {
     InsufficientMinterAllowance = 60023,
     /// Contract is currently paused, this functionality therefore is unavailable.
     ContractIsPaused = 60024,
+    /// The last remaining Owner cannot be removed.
+    CannotRemoveLastOwner = 60025,
+    /// The account does not hold the role it is supposed to give up.
+    MissingRole = 60026,
+    /// The role cannot be handed over.
+    InvalidRole = 60027,
+    /// There already is a pending handover of the role to this nominee.
+    RoleTransferPending = 60028,
+    /// There is no pending handover of the role to this nominee.
+    MissingRoleTransfer = 60029,
+    /// There is no pending proposal with this id.
+    MissingProposal = 60030,
+    /// The proposal has expired.
+    ProposalExpired = 60031,
+    /// The Owner has already approved this proposal.
+    ProposalAlreadyApproved = 60032,
+    /// The approval threshold must be between one and the number of Owners.
+    InvalidThreshold = 60033,
+    /// There is no queued action with this id.
+    MissingQueuedAction = 60034,
+    /// The delay of the queued action has not passed yet.
+    TimelockNotElapsed = 60035,
+    /// Built-in roles can only be managed through their dedicated entrypoints.
+    ReservedRole = 60036,
+    /// The Minter is not paired with the calling Controller.
+    MinterNotControlled = 60037,
+    /// The Minter is already paired with a Controller.
+    MinterAlreadyControlled = 60038,
+    /// The Minter has reached its limit for the current window.
+    MintRateLimitExceeded = 60039,
+    /// The total supply would exceed the maximum supply.
+    SupplyCapExceeded = 60040,
+    /// There is no pending redemption with the given id.
+    MissingRedemption = 60041,
+    /// The account is not blacklisted.
+    AccountNotBlacklisted = 60042,
+    /// The account is not allowlisted.
+    NotAllowlisted = 60043,
+    /// The amount exceeds the balance that is not frozen.
+    InsufficientAvailableBalance = 60044,
+    /// The deadline of the signed message has passed.
+    PermitExpired = 60045,
+    /// The nonce of the signed message has already been used or is out of order.
+    InvalidNonce = 60046,
+    /// The signature does not match the message and public key.
+    InvalidSignature = 60047,
+    /// The transfer authorization is not valid yet.
+    AuthorizationNotYetValid = 60048,
+    /// The transfer authorization is no longer valid.
+    AuthorizationExpired = 60049,
+    /// The transfer authorization has already been used or cancelled.
+    AuthorizationUsed = 60050,
+    /// Only the recipient can submit a receive authorization.
+    InvalidPayee = 60051,
+    /// The transfer memo exceeds the maximum length.
+    MemoTooLong = 60052,
+    /// The receiving contract rejected the tokens.
+    TransferRejected = 60053,
+    /// The spending contract rejected the allowance.
+    ApprovalRejected = 60054,
+    /// The contract holds fewer rescuable tokens than requested.
+    InsufficientRescuableBalance = 60055,
+    /// The action needs the approval of several Owners and must be proposed.
+    ProposalRequired = 60056,
+    /// The role handover nomination has expired or its proposer lost the right to make it.
+    RoleTransferExpired = 60057,
+    /// The action is carried out internally and cannot be proposed.
+    InvalidAction = 60058,
     /// The contract is in an invalid state. This error should never happen.
     InvalidState = 60100,
     /// Trying to burn an invalid amount e.g. 0
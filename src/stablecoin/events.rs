@@ -1,4 +1,5 @@
 use crate::stablecoin::storage::Roles::Role;
+use crate::stablecoin::utils::PausableOperation;
 use odra::casper_types::U256;
 use odra::prelude::*;
 use odra::Address;
@@ -88,6 +89,9 @@ pub struct TransferFrom {
 /// Emitted when account ID is blacklisted.
 pub struct Blacklist {
     pub account: Address,
+    pub reason_code: u32,
+    pub reference: String,
+    pub expires_at: Option<u64>,
 }
 
 #[odra::event]
@@ -114,6 +118,8 @@ pub struct ControllerRemoved {
 pub struct MinterConfigured {
     pub minter: Address,
     pub minter_allowance: U256,
+    pub window: u64,
+    pub window_limit: U256,
 }
 
 #[odra::event]
@@ -130,6 +136,18 @@ pub struct Paused {}
 /// Emitted when contract is unpaused.
 pub struct Unpaused {}
 
+#[odra::event]
+/// Emitted when a single operation is paused.
+pub struct OperationPaused {
+    pub operation: PausableOperation,
+}
+
+#[odra::event]
+/// Emitted when a single operation is unpaused.
+pub struct OperationUnpaused {
+    pub operation: PausableOperation,
+}
+
 #[odra::event]
 /// Emitted when an account is configured as one of the contract's main multi-sig roles, e.g.
 /// Admin, MasterMinter, etc.
@@ -146,6 +164,190 @@ pub struct RoleRevoked {
     pub account: Address,
 }
 
+#[odra::event]
+/// Emitted when a role handover to a nominee is proposed.
+pub struct RoleTransferProposed {
+    pub role: Role,
+    pub proposer: Address,
+    pub previous_holder: Address,
+    pub nominee: Address,
+}
+
+#[odra::event]
+/// Emitted when a nominee accepts a role handover.
+pub struct RoleTransferAccepted {
+    pub role: Role,
+    pub previous_holder: Address,
+    pub new_holder: Address,
+}
+
+#[odra::event]
+/// Emitted when the proposer or an Owner cancels a pending role handover.
+pub struct RoleTransferCancelled {
+    pub role: Role,
+    pub proposer: Address,
+    pub nominee: Address,
+}
+
+#[odra::event]
+/// Emitted when an Owner proposes an admin action.
+pub struct ProposalCreated {
+    pub id: u32,
+    pub proposer: Address,
+    pub expires_at: u64,
+}
+
+#[odra::event]
+/// Emitted when an Owner approves a proposal, with the number of current Owners that approved it.
+pub struct ProposalApproved {
+    pub id: u32,
+    pub approver: Address,
+    pub approvals: u32,
+}
+
+#[odra::event]
+/// Emitted when a proposal reaches the threshold and its action is carried out.
+pub struct ProposalExecuted {
+    pub id: u32,
+}
+
+#[odra::event]
+/// Emitted when the proposer cancels a proposal.
+pub struct ProposalCancelled {
+    pub id: u32,
+}
+
+#[odra::event]
+/// Emitted when a sensitive action is queued by the timelock.
+pub struct ActionQueued {
+    pub id: u32,
+    pub ready_at: u64,
+}
+
+#[odra::event]
+/// Emitted when a queued action is carried out.
+pub struct ActionExecuted {
+    pub id: u32,
+}
+
+#[odra::event]
+/// Emitted when a Guardian cancels a queued action.
+pub struct ActionCancelled {
+    pub id: u32,
+}
+
+#[odra::event]
+/// Emitted when a holder moves tokens into the redemption escrow.
+pub struct RedemptionRequested {
+    pub id: u32,
+    pub holder: Address,
+    pub amount: U256,
+    pub reference: String,
+}
+
+#[odra::event]
+/// Emitted when a minter burns the tokens of a redemption request.
+pub struct RedemptionFinalized {
+    pub id: u32,
+    pub minter: Address,
+}
+
+#[odra::event]
+/// Emitted when a minter refunds the tokens of a redemption request.
+pub struct RedemptionRejected {
+    pub id: u32,
+    pub minter: Address,
+}
+
+#[odra::event]
+/// Emitted when the maximum supply is changed.
+pub struct MaxSupplyChanged {
+    pub previous_max_supply: U256,
+    pub new_max_supply: U256,
+}
+
+#[odra::event]
+/// Emitted when the timelock is reconfigured.
+pub struct TimelockConfigured {
+    pub delay: u64,
+    pub allowance_threshold: U256,
+}
+
+#[odra::event]
+/// Emitted when the admin role of a role is changed.
+pub struct RoleAdminChanged {
+    pub role: Role,
+    pub previous_admin_role: Role,
+    pub new_admin_role: Role,
+}
+
+#[odra::event]
+/// Emitted when the balance of a blacklisted account is burned or moved to a recovery account.
+pub struct Seized {
+    pub account: Address,
+    pub amount: U256,
+    pub recovery: Option<Address>,
+}
+
+#[odra::event]
+/// Emitted when an account is added to the allowlist.
+pub struct Allowlisted {
+    pub account: Address,
+}
+
+#[odra::event]
+/// Emitted when an account is removed from the allowlist.
+pub struct Unallowlisted {
+    pub account: Address,
+}
+
+#[odra::event]
+/// Emitted when part of the balance of an account is frozen.
+pub struct Frozen {
+    pub account: Address,
+    pub amount: U256,
+    pub frozen_balance: U256,
+}
+
+#[odra::event]
+/// Emitted when part of the frozen balance of an account is released.
+pub struct Unfrozen {
+    pub account: Address,
+    pub amount: U256,
+    pub frozen_balance: U256,
+}
+
+#[odra::event]
+/// Emitted alongside `Transfer` when a transfer carries a payment reference.
+pub struct TransferWithMemo {
+    pub sender: Address,
+    pub recipient: Address,
+    pub amount: U256,
+    pub memo: String,
+}
+
+#[odra::event]
+/// Emitted when a signed transfer authorization is carried out.
+pub struct AuthorizationUsed {
+    pub authorizer: Address,
+    pub nonce: [u8; 32],
+}
+
+#[odra::event]
+/// Emitted when a signed transfer authorization is cancelled.
+pub struct AuthorizationCanceled {
+    pub authorizer: Address,
+    pub nonce: [u8; 32],
+}
+
+#[odra::event]
+/// Emitted when tokens held by the contract are moved out by a Rescuer.
+pub struct TokensRescued {
+    pub token_contract: Address,
+    pub recipient: Address,
+    pub amount: U256,
+}
+
 #[odra::event]
 /// Emitted when account ID is unblacklisted.
 pub struct Unblacklist {
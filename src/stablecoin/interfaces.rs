@@ -0,0 +1,32 @@
+use odra::casper_types::bytesrepr::Bytes;
+use odra::casper_types::U256;
+use odra::Address;
+
+/// Entrypoint a contract exposes to be notified of tokens sent with `transfer_and_call`.
+#[odra::external_contract]
+pub trait StablecoinReceiver {
+    /// Called after `amount` tokens were moved from `from` to the receiver on behalf of `operator`.
+    /// Returning false rejects the tokens and reverts the transfer.
+    fn on_stablecoin_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> bool;
+}
+
+/// Entrypoint a contract exposes to be notified of allowances granted with `approve_and_call`.
+#[odra::external_contract]
+pub trait StablecoinApprovalReceiver {
+    /// Called after `owner` allowed the receiver to spend `amount` of their tokens.
+    /// Returning false rejects the allowance and reverts the approval.
+    fn on_approval_received(&mut self, owner: Address, amount: U256, data: Bytes) -> bool;
+}
+
+/// The part of the CEP-18 interface used to move foreign tokens held by the contract.
+#[odra::external_contract]
+pub trait Cep18Token {
+    /// Transfers tokens from the caller to the recipient.
+    fn transfer(&mut self, recipient: &Address, amount: &U256);
+}
@@ -3,21 +3,34 @@ use odra::casper_types::U256;
 use odra::ExecutionError::AdditionOverflow;
 
 use odra::casper_types::bytesrepr::ToBytes;
-use odra::{prelude::*, Mapping};
+use odra::{prelude::*, Mapping, Var};
 use odra::{Address, UnwrapOrRevert};
 
-use crate::stablecoin::errors::Error::{InvalidState, Overflow};
+use crate::stablecoin::errors::Error::{
+    AuthorizationUsed, InvalidNonce, InvalidState, MintRateLimitExceeded, MissingProposal,
+    MissingQueuedAction, MissingRedemption, Overflow, ProposalAlreadyApproved, ProposalExpired,
+};
+use crate::stablecoin::utils::{
+    AdminAction, BlacklistEntry, MintWindow, PendingRoleTransfer, Proposal, ProposalStatus,
+    QueuedAction, QueuedActionStatus, Redemption, RedemptionStatus,
+};
 
 use base64::prelude::*;
 
-use super::events::{RoleConfigured, RoleRevoked};
+use super::events::{
+    ActionCancelled, ActionExecuted, ActionQueued, ProposalApproved, ProposalCancelled,
+    ProposalCreated, ProposalExecuted, RedemptionFinalized, RedemptionRejected,
+    RedemptionRequested, RoleAdminChanged, RoleConfigured, RoleRevoked, TimelockConfigured,
+};
 const ALLOWANCES_KEY: &str = "allowances";
 const MINTER_ALLOWANCES_KEY: &str = "minter_allowances";
 const BALANCES_KEY: &str = "balances";
+const FROZEN_BALANCES_KEY: &str = "frozen_balances";
 const NAME_KEY: &str = "name";
 const DECIMALS_KEY: &str = "decimals";
 const SYMBOL_KEY: &str = "symbol";
 const TOTAL_SUPPLY_KEY: &str = "total_supply";
+const MAX_SUPPLY_KEY: &str = "max_supply";
 
 #[odra::module]
 /// Storage module for the name of the token.
@@ -111,6 +124,18 @@ impl StablecoinTotalSupplyStorage {
             .unwrap_or_revert_with(&self.env(), Overflow);
         self.set(new_total_supply);
     }
+
+    /// Sets the maximum supply of the token.
+    pub fn set_max(&self, max_supply: U256) {
+        self.env().set_named_value(MAX_SUPPLY_KEY, max_supply);
+    }
+
+    /// Gets the maximum supply of the token, unlimited unless configured.
+    pub fn max(&self) -> U256 {
+        self.env()
+            .get_named_value(MAX_SUPPLY_KEY)
+            .unwrap_or(U256::MAX)
+    }
 }
 
 #[odra::module]
@@ -148,6 +173,25 @@ impl StablecoinBalancesStorage {
         self.set(account, new_balance);
     }
 
+    /// Sets the frozen portion of the balance of the given account.
+    pub fn set_frozen(&self, account: &Address, frozen: U256) {
+        self.env()
+            .set_dictionary_value(FROZEN_BALANCES_KEY, self.key(account).as_bytes(), frozen);
+    }
+
+    /// Gets the frozen portion of the balance of the given account.
+    pub fn get_frozen_or_default(&self, account: &Address) -> U256 {
+        self.env()
+            .get_dictionary_value(FROZEN_BALANCES_KEY, self.key(account).as_bytes())
+            .unwrap_or_default()
+    }
+
+    /// Gets the balance of the given account that is not frozen.
+    pub fn available(&self, account: &Address) -> U256 {
+        self.get_or_default(account)
+            .saturating_sub(self.get_frozen_or_default(account))
+    }
+
     fn key(&self, owner: &Address) -> String {
         // PRENOTE: This note is copied from the original implementation of CEP-18.
         // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
@@ -274,27 +318,86 @@ pub mod Roles {
     pub const Controller: Role = [5u8; 32];
     #[allow(non_upper_case_globals)]
     pub const Owner: Role = [6u8; 32];
+    #[allow(non_upper_case_globals)]
+    pub const Guardian: Role = [7u8; 32];
+    #[allow(non_upper_case_globals)]
+    pub const Allowlister: Role = [8u8; 32];
+    #[allow(non_upper_case_globals)]
+    pub const Allowlisted: Role = [9u8; 32];
+    /// A custom role managed through `grant_role` and `revoke_role`, administered by Owner.
+    #[allow(non_upper_case_globals)]
+    pub const Rescuer: Role = [10u8; 32];
+
+    /// Returns true for the roles that can be handed over to a nominee.
+    pub fn is_transferable(role: &Role) -> bool {
+        [Owner, MasterMinter, Blacklister, Pauser].contains(role)
+    }
+
+    /// Returns true for the built-in roles, which have dedicated entrypoints.
+    pub fn is_reserved(role: &Role) -> bool {
+        [
+            Minter,
+            MasterMinter,
+            Blacklister,
+            Blacklisted,
+            Pauser,
+            Controller,
+            Owner,
+            Guardian,
+            Allowlister,
+            Allowlisted,
+        ]
+        .contains(role)
+    }
 }
 
-#[odra::module(events=[RoleConfigured, RoleRevoked])]
+#[odra::module(events=[RoleConfigured, RoleRevoked, RoleAdminChanged])]
 /// Storage module for the allowances of the token.
 pub struct StablecoinRoles {
     roles: Mapping<(Roles::Role, Address), bool>,
+    members_count: Mapping<Roles::Role, u32>,
+    // Members of each role by position, kept dense by swapping the last member into a gap
+    members: Mapping<(Roles::Role, u32), Address>,
+    member_positions: Mapping<(Roles::Role, Address), u32>,
+    // The role whose holders manage a role, Owner unless configured otherwise
+    admins: Mapping<Roles::Role, Roles::Role>,
+    blacklist_entries: Mapping<Address, Option<BlacklistEntry>>,
+    // The Blacklister for this Contract
+    blacklister: Var<Address>,
+    // Role handovers awaiting acceptance, keyed by role and nominee
+    pending_transfers: Mapping<(Roles::Role, Address), Option<PendingRoleTransfer>>,
 }
 
 #[odra::module]
 impl StablecoinRoles {
     pub fn configure_role(&mut self, role: &Roles::Role, account: &Address) {
-        self.roles.set(&(*role, *account), true);
-        self.env().emit_event(RoleConfigured {
-            role: *role,
-            account: *account,
-        });
+        if !self.has_role(role, account) {
+            let count = self.role_member_count(role);
+            self.roles.set(&(*role, *account), true);
+            self.members.set(&(*role, count), *account);
+            self.member_positions.set(&(*role, *account), count);
+            self.members_count.set(role, count + 1);
+            self.env().emit_event(RoleConfigured {
+                role: *role,
+                account: *account,
+            });
+        }
     }
 
     pub fn revoke_role(&mut self, role: &Roles::Role, account: &Address) {
         if self.has_role(role, account) {
+            let last = self.role_member_count(role) - 1;
+            let position = self.member_positions.get_or_default(&(*role, *account));
+            if position != last {
+                let last_member = self
+                    .members
+                    .get(&(*role, last))
+                    .unwrap_or_revert_with(&self.env(), InvalidState);
+                self.members.set(&(*role, position), last_member);
+                self.member_positions.set(&(*role, last_member), position);
+            }
             self.roles.set(&(*role, *account), false);
+            self.members_count.set(role, last);
             self.env().emit_event(RoleRevoked {
                 role: *role,
                 account: *account,
@@ -312,7 +415,28 @@ impl StablecoinRoles {
         self.has_role(&Roles::Blacklister, account)
     }
     pub fn is_blacklisted(&self, account: &Address) -> bool {
-        self.has_role(&Roles::Blacklisted, account)
+        if !self.has_role(&Roles::Blacklisted, account) {
+            return false;
+        }
+        match self
+            .blacklist_entry(account)
+            .and_then(|entry| entry.expires_at)
+        {
+            Some(expires_at) => self.env().get_block_time() < expires_at,
+            None => true,
+        }
+    }
+    pub fn blacklister(&self) -> Option<Address> {
+        self.blacklister.get()
+    }
+    pub fn set_blacklister(&mut self, blacklister: &Address) {
+        self.blacklister.set(*blacklister);
+    }
+    pub fn blacklist_entry(&self, account: &Address) -> Option<BlacklistEntry> {
+        self.blacklist_entries.get_or_default(account)
+    }
+    pub fn set_blacklist_entry(&mut self, account: &Address, entry: Option<BlacklistEntry>) {
+        self.blacklist_entries.set(account, entry);
     }
     pub fn is_pauser(&self, account: &Address) -> bool {
         self.has_role(&Roles::Pauser, account)
@@ -323,7 +447,441 @@ impl StablecoinRoles {
     pub fn is_owner(&self, account: &Address) -> bool {
         self.has_role(&Roles::Owner, account)
     }
+    pub fn is_guardian(&self, account: &Address) -> bool {
+        self.has_role(&Roles::Guardian, account)
+    }
+    pub fn is_allowlister(&self, account: &Address) -> bool {
+        self.has_role(&Roles::Allowlister, account)
+    }
+    pub fn is_allowlisted(&self, account: &Address) -> bool {
+        self.has_role(&Roles::Allowlisted, account)
+    }
     pub fn has_role(&self, role: &Roles::Role, account: &Address) -> bool {
         self.roles.get_or_default(&(*role, *account))
     }
+    pub fn role_admin(&self, role: &Roles::Role) -> Roles::Role {
+        self.admins.get(role).unwrap_or(Roles::Owner)
+    }
+    pub fn set_role_admin(&mut self, role: &Roles::Role, admin_role: &Roles::Role) {
+        let previous_admin_role = self.role_admin(role);
+        self.admins.set(role, *admin_role);
+        self.env().emit_event(RoleAdminChanged {
+            role: *role,
+            previous_admin_role,
+            new_admin_role: *admin_role,
+        });
+    }
+    pub fn pending_transfer(
+        &self,
+        role: &Roles::Role,
+        nominee: &Address,
+    ) -> Option<PendingRoleTransfer> {
+        self.pending_transfers.get_or_default(&(*role, *nominee))
+    }
+    pub fn set_pending_transfer(
+        &mut self,
+        role: &Roles::Role,
+        nominee: &Address,
+        pending: Option<PendingRoleTransfer>,
+    ) {
+        self.pending_transfers.set(&(*role, *nominee), pending);
+    }
+    pub fn role_member_count(&self, role: &Roles::Role) -> u32 {
+        self.members_count.get_or_default(role)
+    }
+    pub fn role_members(&self, role: &Roles::Role, offset: u32, limit: u32) -> Vec<Address> {
+        let end = offset
+            .saturating_add(limit)
+            .min(self.role_member_count(role));
+        (offset..end)
+            .filter_map(|position| self.members.get(&(*role, position)))
+            .collect()
+    }
+}
+
+#[odra::module]
+/// Storage module for the minters managed by each controller and their mint rate limits.
+pub struct StablecoinControllers {
+    minters_count: Mapping<Address, u32>,
+    // Minters of each controller by position, kept dense like the role members
+    minters: Mapping<(Address, u32), Address>,
+    minter_positions: Mapping<Address, u32>,
+    minter_controllers: Mapping<Address, Option<Address>>,
+    windows: Mapping<Address, Option<MintWindow>>,
+}
+
+#[odra::module]
+impl StablecoinControllers {
+    /// Pairs an unpaired minter with the controller.
+    pub fn pair(&mut self, controller: &Address, minter: &Address) {
+        let count = self.minters_count.get_or_default(controller);
+        self.minters.set(&(*controller, count), *minter);
+        self.minter_positions.set(minter, count);
+        self.minters_count.set(controller, count + 1);
+        self.minter_controllers.set(minter, Some(*controller));
+    }
+
+    /// Removes the minter from its controller, if it has one.
+    pub fn unpair(&mut self, minter: &Address) {
+        if let Some(controller) = self.controller_of(minter) {
+            let last = self.minters_count.get_or_default(&controller) - 1;
+            let position = self.minter_positions.get_or_default(minter);
+            if position != last {
+                let last_minter = self
+                    .minters
+                    .get(&(controller, last))
+                    .unwrap_or_revert_with(&self.env(), InvalidState);
+                self.minters.set(&(controller, position), last_minter);
+                self.minter_positions.set(&last_minter, position);
+            }
+            self.minters_count.set(&controller, last);
+            self.minter_controllers.set(minter, None);
+        }
+    }
+
+    /// Returns the controller of the minter.
+    pub fn controller_of(&self, minter: &Address) -> Option<Address> {
+        self.minter_controllers.get_or_default(minter)
+    }
+
+    /// Returns all minters of the controller.
+    pub fn minters_of(&self, controller: &Address) -> Vec<Address> {
+        (0..self.minters_count.get_or_default(controller))
+            .filter_map(|position| self.minters.get(&(*controller, position)))
+            .collect()
+    }
+
+    /// Sets the window of the minter, a zero duration removes the limit.
+    /// An existing bucket keeps what has not drained yet, up to the new limit.
+    pub fn configure_window(&mut self, minter: &Address, duration: u64, limit: U256) {
+        let now = self.env().get_block_time();
+        let used = self
+            .window(minter)
+            .map(|window| window.used_at(now).min(limit))
+            .unwrap_or_default();
+        let window = (duration > 0).then_some(MintWindow {
+            duration,
+            limit,
+            updated_at: now,
+            used,
+        });
+        self.windows.set(minter, window);
+    }
+
+    /// Gets the window of the minter, if it is rate limited.
+    pub fn window(&self, minter: &Address) -> Option<MintWindow> {
+        self.windows.get_or_default(minter)
+    }
+
+    /// Records the amount minted by the minter, reverts if it does not fit in the bucket.
+    pub fn consume_window(&mut self, minter: &Address, amount: U256) {
+        if let Some(mut window) = self.window(minter) {
+            let now = self.env().get_block_time();
+            match window.used_at(now).checked_add(amount) {
+                Some(used) if used <= window.limit => window.used = used,
+                _ => self.env().revert(MintRateLimitExceeded),
+            }
+            window.updated_at = now;
+            self.windows.set(minter, Some(window));
+        }
+    }
+}
+
+#[odra::module(events=[ProposalCreated, ProposalApproved, ProposalExecuted, ProposalCancelled])]
+/// Storage module for the admin actions proposed by Owners.
+pub struct StablecoinProposals {
+    proposals: Mapping<u32, Proposal>,
+    approvals: Mapping<(u32, Address), bool>,
+    next_id: Var<u32>,
+    threshold: Var<u32>,
+}
+
+#[odra::module]
+impl StablecoinProposals {
+    /// Stores a new pending proposal and returns its id.
+    pub fn create(&mut self, action: AdminAction, proposer: &Address, expires_at: u64) -> u32 {
+        let id = self.next_id.get_or_default();
+        self.next_id.set(id + 1);
+        self.proposals.set(
+            &id,
+            Proposal {
+                action,
+                proposer: *proposer,
+                approvals: 0,
+                expires_at,
+                status: ProposalStatus::Pending,
+            },
+        );
+        self.env().emit_event(ProposalCreated {
+            id,
+            proposer: *proposer,
+            expires_at,
+        });
+        id
+    }
+
+    /// Gets the proposal with the given id.
+    pub fn get(&self, id: u32) -> Option<Proposal> {
+        self.proposals.get(&id)
+    }
+
+    /// Gets the proposal with the given id, reverts if it is not pending.
+    pub fn get_pending(&self, id: u32) -> Proposal {
+        match self.get(id) {
+            Some(proposal) if proposal.status == ProposalStatus::Pending => proposal,
+            _ => self.env().revert(MissingProposal),
+        }
+    }
+
+    /// Records the approval of the given account, along with the number of current Owners
+    /// that approved the proposal so far.
+    pub fn approve(&mut self, id: u32, approver: &Address, approvals: u32) {
+        let mut proposal = self.get_pending(id);
+        if self.env().get_block_time() > proposal.expires_at {
+            self.env().revert(ProposalExpired);
+        }
+        if self.approvals.get_or_default(&(id, *approver)) {
+            self.env().revert(ProposalAlreadyApproved);
+        }
+        self.approvals.set(&(id, *approver), true);
+        proposal.approvals = approvals;
+        self.proposals.set(&id, proposal);
+        self.env().emit_event(ProposalApproved {
+            id,
+            approver: *approver,
+            approvals,
+        });
+    }
+
+    /// Returns true if the account approved the proposal.
+    pub fn has_approved(&self, id: u32, account: &Address) -> bool {
+        self.approvals.get_or_default(&(id, *account))
+    }
+
+    /// Marks the proposal as executed.
+    pub fn mark_executed(&mut self, id: u32) {
+        self.set_status(id, ProposalStatus::Executed);
+        self.env().emit_event(ProposalExecuted { id });
+    }
+
+    /// Marks the proposal as cancelled.
+    pub fn cancel(&mut self, id: u32) {
+        self.set_status(id, ProposalStatus::Cancelled);
+        self.env().emit_event(ProposalCancelled { id });
+    }
+
+    /// Gets the number of approvals a proposal needs, one unless configured otherwise.
+    pub fn threshold(&self) -> u32 {
+        self.threshold.get().unwrap_or(1)
+    }
+
+    /// Sets the number of approvals a proposal needs.
+    pub fn set_threshold(&mut self, threshold: u32) {
+        self.threshold.set(threshold);
+    }
+
+    fn set_status(&mut self, id: u32, status: ProposalStatus) {
+        let mut proposal = self.get_pending(id);
+        proposal.status = status;
+        self.proposals.set(&id, proposal);
+    }
+}
+
+#[odra::module(events=[ActionQueued, ActionExecuted, ActionCancelled, TimelockConfigured])]
+/// Storage module for the sensitive admin actions delayed by the timelock.
+pub struct StablecoinTimelock {
+    queue: Mapping<u32, QueuedAction>,
+    next_id: Var<u32>,
+    delay: Var<u64>,
+    allowance_threshold: Var<U256>,
+}
+
+#[odra::module]
+impl StablecoinTimelock {
+    /// Queues the action until the given block time and returns its id.
+    pub fn queue(
+        &mut self,
+        action: AdminAction,
+        ready_at: u64,
+        controller: Option<Address>,
+    ) -> u32 {
+        let id = self.next_id.get_or_default();
+        self.next_id.set(id + 1);
+        self.queue.set(
+            &id,
+            QueuedAction {
+                action,
+                ready_at,
+                status: QueuedActionStatus::Queued,
+                controller,
+            },
+        );
+        self.env().emit_event(ActionQueued { id, ready_at });
+        id
+    }
+
+    /// Gets the queued action with the given id.
+    pub fn get(&self, id: u32) -> Option<QueuedAction> {
+        self.queue.get(&id)
+    }
+
+    /// Gets the queued action with the given id, reverts if it is no longer queued.
+    pub fn get_queued(&self, id: u32) -> QueuedAction {
+        match self.get(id) {
+            Some(queued) if queued.status == QueuedActionStatus::Queued => queued,
+            _ => self.env().revert(MissingQueuedAction),
+        }
+    }
+
+    /// Marks the queued action as executed.
+    pub fn mark_executed(&mut self, id: u32) {
+        self.set_status(id, QueuedActionStatus::Executed);
+        self.env().emit_event(ActionExecuted { id });
+    }
+
+    /// Marks the queued action as cancelled.
+    pub fn cancel(&mut self, id: u32) {
+        self.set_status(id, QueuedActionStatus::Cancelled);
+        self.env().emit_event(ActionCancelled { id });
+    }
+
+    /// Gets the delay in milliseconds, zero if the timelock is disabled.
+    pub fn delay(&self) -> u64 {
+        self.delay.get_or_default()
+    }
+
+    /// Gets the minter allowance above which allowance changes are delayed.
+    pub fn allowance_threshold(&self) -> U256 {
+        self.allowance_threshold.get().unwrap_or(U256::MAX)
+    }
+
+    /// Sets the delay and the minter allowance above which allowance changes are delayed.
+    pub fn configure(&mut self, delay: u64, allowance_threshold: U256) {
+        self.delay.set(delay);
+        self.allowance_threshold.set(allowance_threshold);
+        self.env().emit_event(TimelockConfigured {
+            delay,
+            allowance_threshold,
+        });
+    }
+
+    fn set_status(&mut self, id: u32, status: QueuedActionStatus) {
+        let mut queued = self.get_queued(id);
+        queued.status = status;
+        self.queue.set(&id, queued);
+    }
+}
+
+#[odra::module]
+/// Storage module for the replay protection of signed approvals and transfers.
+pub struct StablecoinSignatures {
+    nonces: Mapping<Address, u64>,
+    // Transfer authorizations that were used or cancelled, keyed by authorizer and nonce
+    authorizations: Mapping<(Address, [u8; 32]), bool>,
+}
+
+#[odra::module]
+impl StablecoinSignatures {
+    /// Gets the nonce the next permit of the owner must use.
+    pub fn nonce(&self, owner: &Address) -> u64 {
+        self.nonces.get_or_default(owner)
+    }
+
+    /// Consumes the nonce of the owner, reverts if it is not the expected one.
+    pub fn use_nonce(&mut self, owner: &Address, nonce: u64) {
+        if nonce != self.nonce(owner) {
+            self.env().revert(InvalidNonce);
+        }
+        self.nonces.set(owner, nonce + 1);
+    }
+
+    /// Returns true if the authorization was used or cancelled.
+    pub fn authorization_state(&self, authorizer: &Address, nonce: &[u8; 32]) -> bool {
+        self.authorizations.get_or_default(&(*authorizer, *nonce))
+    }
+
+    /// Records the authorization as used or cancelled, reverts if it already is.
+    pub fn use_authorization(&mut self, authorizer: &Address, nonce: &[u8; 32]) {
+        if self.authorization_state(authorizer, nonce) {
+            self.env().revert(AuthorizationUsed);
+        }
+        self.authorizations.set(&(*authorizer, *nonce), true);
+    }
+}
+
+#[odra::module(events=[RedemptionRequested, RedemptionFinalized, RedemptionRejected])]
+/// Storage module for the redemption requests and the tokens they hold in escrow.
+pub struct StablecoinRedemptions {
+    requests: Mapping<u32, Redemption>,
+    next_id: Var<u32>,
+    escrowed: Var<U256>,
+}
+
+#[odra::module]
+impl StablecoinRedemptions {
+    /// Records a pending redemption of the escrowed amount and returns its id.
+    pub fn create(&mut self, holder: &Address, amount: U256, reference: String) -> u32 {
+        let id = self.next_id.get_or_default();
+        self.next_id.set(id + 1);
+        self.escrowed.set(self.escrowed() + amount);
+        self.requests.set(
+            &id,
+            Redemption {
+                holder: *holder,
+                amount,
+                reference: reference.clone(),
+                status: RedemptionStatus::Pending,
+            },
+        );
+        self.env().emit_event(RedemptionRequested {
+            id,
+            holder: *holder,
+            amount,
+            reference,
+        });
+        id
+    }
+
+    /// Gets the redemption with the given id.
+    pub fn get(&self, id: u32) -> Option<Redemption> {
+        self.requests.get(&id)
+    }
+
+    /// Gets the redemption with the given id, reverts if it is not pending.
+    pub fn get_pending(&self, id: u32) -> Redemption {
+        match self.get(id) {
+            Some(redemption) if redemption.status == RedemptionStatus::Pending => redemption,
+            _ => self.env().revert(MissingRedemption),
+        }
+    }
+
+    /// Marks the redemption as finalized and releases it from the escrow.
+    pub fn finalize(&mut self, id: u32, minter: &Address) {
+        self.release(id, RedemptionStatus::Finalized);
+        self.env().emit_event(RedemptionFinalized {
+            id,
+            minter: *minter,
+        });
+    }
+
+    /// Marks the redemption as rejected and releases it from the escrow.
+    pub fn reject(&mut self, id: u32, minter: &Address) {
+        self.release(id, RedemptionStatus::Rejected);
+        self.env().emit_event(RedemptionRejected {
+            id,
+            minter: *minter,
+        });
+    }
+
+    /// Gets the amount held in escrow by pending redemptions.
+    pub fn escrowed(&self) -> U256 {
+        self.escrowed.get_or_default()
+    }
+
+    fn release(&mut self, id: u32, status: RedemptionStatus) {
+        let mut redemption = self.get_pending(id);
+        self.escrowed.set(self.escrowed() - redemption.amount);
+        redemption.status = status;
+        self.requests.set(&id, redemption);
+    }
 }
@@ -1,13 +1,18 @@
 #[cfg(test)]
 mod allowance_tests {
-    use crate::stablecoin::errors::Error::InsufficientAllowance;
+    use crate::stablecoin::errors::Error::{ApprovalRejected, InsufficientAllowance};
     use crate::stablecoin::tests::client_contract_test::StablecoinClientContractHostRef;
     use crate::stablecoin::setup_tests::{
         invert_address, setup, setup_with_args, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2,
         TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
     };
+    use crate::stablecoin::tests::receiver_contract_test::{
+        StablecoinReceiverContractHostRef, StablecoinReceiverContractInitArgs,
+    };
     use crate::stablecoin::{StablecoinHostRef, StablecoinInitArgs};
+    use alloc::vec;
     use core::ops::Add;
+    use odra::casper_types::bytesrepr::Bytes;
     use odra::casper_types::U256;
     use odra::host::{Deployer, HostRef, NoArgs};
     use odra::Address;
@@ -165,7 +170,7 @@ mod allowance_tests {
         env.set_caller(master_minter);
         stablecoin.configure_controller(&controller_1, &minter_1);
         env.set_caller(controller_1);
-        stablecoin.increase_minter_allowance(U256::from(10));
+        stablecoin.increase_minter_allowance(&minter_1, U256::from(10));
         assert_eq!(stablecoin.minter_allowance(&minter_1), U256::from(10));
     }
     #[test]
@@ -190,8 +195,46 @@ mod allowance_tests {
         env.set_caller(master_minter);
         stablecoin.configure_controller(&controller_1, &minter_1);
         env.set_caller(controller_1);
-        stablecoin.increase_minter_allowance(U256::from(10));
-        stablecoin.decrease_minter_allowance(U256::from(5));
+        stablecoin.increase_minter_allowance(&minter_1, U256::from(10));
+        stablecoin.decrease_minter_allowance(&minter_1, U256::from(5));
         assert_eq!(stablecoin.minter_allowance(&minter_1), U256::from(5));
     }
+
+    #[test]
+    fn should_approve_and_call() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let amount: U256 = ALLOWANCE_AMOUNT_1.into();
+        let receiver = StablecoinReceiverContractHostRef::deploy(
+            &env,
+            StablecoinReceiverContractInitArgs { accept: true },
+        );
+
+        // the receiver pulls the approved tokens within the same call
+        stablecoin.approve_and_call(receiver.address(), &amount, Bytes::from(vec![7]));
+        assert!(
+            stablecoin.env().emitted(&stablecoin, "SetAllowance"),
+            "SetAllowance event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(receiver.address()), amount);
+        assert_eq!(stablecoin.allowance(&owner, receiver.address()), 0.into());
+        assert_eq!(receiver.last_from(), Some(owner));
+    }
+
+    #[test]
+    fn should_not_approve_and_call_rejecting_receiver() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let receiver = StablecoinReceiverContractHostRef::deploy(
+            &env,
+            StablecoinReceiverContractInitArgs { accept: false },
+        );
+        let result = stablecoin.try_approve_and_call(
+            receiver.address(),
+            &ALLOWANCE_AMOUNT_1.into(),
+            Bytes::new(),
+        );
+        assert_eq!(result.err().unwrap(), ApprovalRejected.into());
+        assert_eq!(stablecoin.allowance(&owner, receiver.address()), 0.into());
+    }
 }
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod allowlist_tests {
+    use crate::stablecoin::errors::Error::{InsufficientRights, NotAllowlisted};
+    use crate::stablecoin::setup_tests::{setup_with, TOKEN_TOTAL_SUPPLY};
+    use crate::stablecoin::utils::StablecoinModality;
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::casper_types::U256;
+    use odra::host::HostEnv;
+    use odra::Address;
+
+    fn setup() -> (HostEnv, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let owner = env.get_account(7);
+        let allowlister = env.get_account(8);
+        // the deployer holds the initial supply
+        let holder = env.get_account(0);
+        let mut stablecoin = setup_with(&env, vec![owner], StablecoinModality::Allowlist);
+        env.set_caller(owner);
+        stablecoin.add_allowlister(&allowlister);
+        (env, allowlister, holder, stablecoin)
+    }
+
+    #[test]
+    fn test_only_allowlisted_accounts_transact() {
+        let (env, allowlister, holder, mut stablecoin) = setup();
+        let user = env.get_account(6);
+        env.set_caller(holder);
+        let result = stablecoin.try_transfer(&user, &U256::one());
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+
+        env.set_caller(allowlister);
+        stablecoin.allowlist(&holder);
+        assert!(
+            env.emitted(&stablecoin, "Allowlisted"),
+            "Allowlisted event not emitted"
+        );
+        env.set_caller(holder);
+        let result = stablecoin.try_approve(&user, &U256::one());
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+
+        env.set_caller(allowlister);
+        stablecoin.allowlist(&user);
+        env.set_caller(holder);
+        stablecoin.transfer(&user, &U256::one());
+        assert_eq!(stablecoin.balance_of(&user), U256::one());
+
+        env.set_caller(allowlister);
+        stablecoin.unallowlist(&user);
+        assert!(!stablecoin.is_allowlisted(&user));
+        env.set_caller(user);
+        let result = stablecoin.try_transfer(&holder, &U256::one());
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+    }
+
+    #[test]
+    fn test_mint_requires_allowlisted_recipient() {
+        let (env, allowlister, _, mut stablecoin) = setup();
+        let (master_minter, controller, minter) =
+            (env.get_account(1), env.get_account(2), env.get_account(3));
+        let user = env.get_account(6);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller, &minter);
+        env.set_caller(controller);
+        stablecoin.configure_minter_allowance(&minter, U256::from(10));
+        env.set_caller(minter);
+        let result = stablecoin.try_mint(&user, U256::from(10));
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+        env.set_caller(user);
+        let result = stablecoin.try_allowlist(&user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(allowlister);
+        stablecoin.allowlist(&user);
+        env.set_caller(minter);
+        stablecoin.mint(&user, U256::from(10));
+        assert_eq!(stablecoin.balance_of(&user), U256::from(10));
+    }
+
+    #[test]
+    fn test_allowances_and_seizures_require_allowlisted_accounts() {
+        let (env, allowlister, holder, mut stablecoin) = setup();
+        let blacklister = env.get_account(4);
+        let user = env.get_account(6);
+        env.set_caller(allowlister);
+        stablecoin.allowlist(&holder);
+        env.set_caller(holder);
+        let result = stablecoin.try_increase_allowance(&user, &U256::one());
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+
+        // seized tokens cannot be moved to an account outside the allowlist
+        env.set_caller(blacklister);
+        stablecoin.blacklist(&holder);
+        let result = stablecoin.try_seize(&holder, Some(user));
+        assert_eq!(result.err().unwrap(), NotAllowlisted.into());
+        env.set_caller(allowlister);
+        stablecoin.allowlist(&user);
+        env.set_caller(blacklister);
+        stablecoin.seize(&holder, Some(user));
+        assert_eq!(stablecoin.balance_of(&user), TOKEN_TOTAL_SUPPLY.into());
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod blacklist_tests {
+    use crate::stablecoin::errors::Error::{
+        AccountNotBlacklisted, InsufficientAvailableBalance, InsufficientRights, InvalidAmount,
+    };
+    use crate::stablecoin::setup_tests::{setup_with, TOKEN_TOTAL_SUPPLY};
+    use crate::stablecoin::storage::Roles;
+    use crate::stablecoin::utils::StablecoinModality;
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::casper_types::U256;
+    use odra::host::HostEnv;
+    use odra::Address;
+
+    fn setup() -> (HostEnv, Address, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let blacklister = env.get_account(4);
+        let owner = env.get_account(7);
+        // the deployer holds the initial supply
+        let holder = env.get_account(0);
+        let stablecoin = setup_with(&env, vec![owner], StablecoinModality::MintAndBurn);
+        (env, blacklister, owner, holder, stablecoin)
+    }
+
+    #[test]
+    fn test_blacklist_many() {
+        let (env, blacklister, owner, holder, mut stablecoin) = setup();
+        let (user_1, user_2) = (env.get_account(8), env.get_account(9));
+        env.set_caller(owner);
+        let result = stablecoin.try_blacklist_many(vec![user_1]);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(blacklister);
+        stablecoin.blacklist(&user_1);
+        assert_eq!(stablecoin.blacklist_many(vec![user_1, user_2, holder]), 2);
+        assert!(stablecoin.is_blacklisted(&user_2));
+        assert!(stablecoin.is_blacklisted(&holder));
+        assert_eq!(stablecoin.role_member_count(Roles::Blacklisted), 3);
+        assert_eq!(stablecoin.unblacklist_many(vec![user_1, user_1, owner]), 1);
+        assert!(!stablecoin.is_blacklisted(&user_1));
+        assert!(
+            env.emitted(&stablecoin, "Unblacklist"),
+            "Unblacklist event not emitted"
+        );
+    }
+
+    #[test]
+    fn test_seize_burns_balance() {
+        let (env, blacklister, _, holder, mut stablecoin) = setup();
+        env.set_caller(blacklister);
+        let result = stablecoin.try_seize(&holder, None);
+        assert_eq!(result.err().unwrap(), AccountNotBlacklisted.into());
+        stablecoin.blacklist(&holder);
+        stablecoin.seize(&holder, None);
+        assert!(
+            env.emitted(&stablecoin, "Seized"),
+            "Seized event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&holder), U256::zero());
+        assert_eq!(stablecoin.total_supply(), U256::zero());
+    }
+
+    #[test]
+    fn test_seize_to_recovery_account() {
+        let (env, blacklister, owner, holder, mut stablecoin) = setup();
+        let recovery = env.get_account(8);
+        env.set_caller(blacklister);
+        stablecoin.blacklist(&holder);
+        env.set_caller(recovery);
+        let result = stablecoin.try_seize(&holder, Some(recovery));
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(owner);
+        stablecoin.seize(&holder, Some(recovery));
+        assert_eq!(stablecoin.balance_of(&holder), U256::zero());
+        assert_eq!(stablecoin.balance_of(&recovery), TOKEN_TOTAL_SUPPLY.into());
+        assert_eq!(stablecoin.total_supply(), TOKEN_TOTAL_SUPPLY.into());
+    }
+
+    #[test]
+    fn test_blacklist_entry_expires() {
+        let (env, blacklister, _, holder, mut stablecoin) = setup();
+        let user = env.get_account(8);
+        let expires_at = env.block_time() + 60_000;
+        env.set_caller(blacklister);
+        stablecoin.blacklist_with_reason(&holder, 3, "case-42".to_string(), Some(expires_at));
+        assert!(
+            env.emitted(&stablecoin, "Blacklist"),
+            "Blacklist event not emitted"
+        );
+        let entry = stablecoin.blacklist_entry(&holder).unwrap();
+        assert_eq!(entry.reason_code, 3);
+        assert_eq!(entry.reference, "case-42");
+        assert_eq!(entry.expires_at, Some(expires_at));
+        assert!(stablecoin.is_blacklisted(&holder));
+        env.set_caller(holder);
+        assert!(stablecoin.try_approve(&user, &U256::one()).is_err());
+
+        // the restriction lapses on its own
+        env.advance_block_time(60_000);
+        assert!(!stablecoin.is_blacklisted(&holder));
+        stablecoin.approve(&user, &U256::one());
+        assert!(stablecoin.blacklist_entry(&holder).is_some());
+        // the lapsed entry is still listed until it is cleaned up
+        assert_eq!(
+            stablecoin.role_members(Roles::Blacklisted, 0, 10),
+            vec![holder]
+        );
+        env.set_caller(blacklister);
+        assert_eq!(stablecoin.unblacklist_many(vec![holder, user]), 1);
+        assert!(stablecoin.blacklist_entry(&holder).is_none());
+        assert_eq!(stablecoin.role_member_count(Roles::Blacklisted), 0);
+    }
+
+    #[test]
+    fn test_freeze_partial_balance() {
+        let (env, blacklister, _, holder, mut stablecoin) = setup();
+        let user = env.get_account(8);
+        let total: U256 = TOKEN_TOTAL_SUPPLY.into();
+        env.set_caller(user);
+        let result = stablecoin.try_freeze(&holder, U256::from(100));
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(blacklister);
+        stablecoin.freeze(&holder, total - 100);
+        assert!(
+            env.emitted(&stablecoin, "Frozen"),
+            "Frozen event not emitted"
+        );
+        assert_eq!(stablecoin.frozen_balance_of(&holder), total - 100);
+        assert_eq!(stablecoin.available_balance_of(&holder), U256::from(100));
+
+        env.set_caller(holder);
+        let result = stablecoin.try_transfer(&user, &U256::from(101));
+        assert_eq!(result.err().unwrap(), InsufficientAvailableBalance.into());
+        stablecoin.transfer(&user, &U256::from(100));
+        assert_eq!(stablecoin.available_balance_of(&holder), U256::zero());
+
+        env.set_caller(blacklister);
+        let result = stablecoin.try_unfreeze(&holder, total);
+        assert_eq!(result.err().unwrap(), InvalidAmount.into());
+        stablecoin.unfreeze(&holder, U256::from(50));
+        assert!(
+            env.emitted(&stablecoin, "Unfrozen"),
+            "Unfrozen event not emitted"
+        );
+        env.set_caller(holder);
+        stablecoin.transfer(&user, &U256::from(50));
+        assert_eq!(stablecoin.balance_of(&holder), total - 150);
+    }
+
+    #[test]
+    fn test_seize_clears_frozen_balance() {
+        let (env, blacklister, _, holder, mut stablecoin) = setup();
+        let user = env.get_account(8);
+        env.set_caller(holder);
+        stablecoin.transfer(&user, &U256::from(100));
+        env.set_caller(blacklister);
+        stablecoin.freeze(&user, U256::from(60));
+        stablecoin.blacklist(&user);
+        stablecoin.seize(&user, None);
+        assert_eq!(stablecoin.frozen_balance_of(&user), U256::zero());
+
+        // tokens received after the account is cleared are not frozen
+        stablecoin.unblacklist(&user);
+        env.set_caller(holder);
+        stablecoin.transfer(&user, &U256::from(10));
+        assert_eq!(stablecoin.available_balance_of(&user), U256::from(10));
+    }
+}
@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod mint_and_burn_tests {
-    use crate::stablecoin::setup_tests::setup;
+    use crate::stablecoin::errors::Error::{
+        InsufficientAllowance, InvalidBurnTarget, MintRateLimitExceeded, SupplyCapExceeded,
+    };
+    use crate::stablecoin::setup_tests::{setup, setup_with, TOKEN_TOTAL_SUPPLY};
+    use crate::stablecoin::utils::StablecoinModality;
+    use alloc::vec;
     use odra::casper_types::U256;
     use odra::host::HostRef;
 
@@ -10,7 +15,7 @@ mod mint_and_burn_tests {
         cep18_token.env().set_caller(master_minter);
         cep18_token.configure_controller(&controller_1, &minter_1);
         cep18_token.env().set_caller(controller_1);
-        cep18_token.configure_minter_allowance(U256::from(10));
+        cep18_token.configure_minter_allowance(&minter_1, U256::from(10));
         cep18_token.env().set_caller(minter_1);
         cep18_token.mint(&user, U256::from(10));
         assert_eq!(cep18_token.minter_allowance(&minter_1), U256::from(0));
@@ -23,11 +28,124 @@ mod mint_and_burn_tests {
         cep18_token.env().set_caller(master_minter);
         cep18_token.configure_controller(&controller_1, &minter_1);
         cep18_token.env().set_caller(controller_1);
-        cep18_token.configure_minter_allowance(U256::from(10));
+        cep18_token.configure_minter_allowance(&minter_1, U256::from(10));
         cep18_token.env().set_caller(minter_1);
         cep18_token.mint(&minter_1, U256::from(10));
         assert!(env.emitted(&cep18_token, "Mint"), "Mint event not emitted");
         cep18_token.burn(U256::from(10));
         assert!(env.emitted(&cep18_token, "Burn"), "Burn event not emitted");
     }
+
+    #[test]
+    fn test_stablecoin_burn_from() {
+        let (env, master_minter, controller_1, minter_1, .., user, mut cep18_token) = setup();
+        env.set_caller(master_minter);
+        cep18_token.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        cep18_token.configure_minter_allowance(&minter_1, U256::from(10));
+        env.set_caller(minter_1);
+        cep18_token.mint(&user, U256::from(10));
+        env.set_caller(user);
+        cep18_token.approve(&minter_1, &U256::from(6));
+        env.set_caller(minter_1);
+        let result = cep18_token.try_burn_from(&minter_1, U256::from(1));
+        assert_eq!(result.err().unwrap(), InvalidBurnTarget.into());
+        let result = cep18_token.try_burn_from(&user, U256::from(7));
+        assert_eq!(result.err().unwrap(), InsufficientAllowance.into());
+        cep18_token.burn_from(&user, U256::from(6));
+        assert!(env.emitted(&cep18_token, "Burn"), "Burn event not emitted");
+        assert!(
+            env.emitted(&cep18_token, "DecreaseAllowance"),
+            "DecreaseAllowance event not emitted"
+        );
+        assert_eq!(cep18_token.balance_of(&user), U256::from(4));
+        assert_eq!(cep18_token.allowance(&user, &minter_1), U256::zero());
+    }
+
+    #[test]
+    fn test_mint_rate_limit() {
+        let (env, master_minter, controller_1, minter_1, .., user, mut cep18_token) = setup();
+        let day: u64 = 86_400_000;
+        env.set_caller(master_minter);
+        cep18_token.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        cep18_token.configure_minter_allowance(&minter_1, U256::from(100));
+        cep18_token.configure_minter_rate_limit(&minter_1, day, U256::from(10));
+        assert!(
+            env.emitted(&cep18_token, "MinterConfigured"),
+            "MinterConfigured event not emitted"
+        );
+        env.set_caller(minter_1);
+        cep18_token.mint(&user, U256::from(6));
+        let result = cep18_token.try_mint(&user, U256::from(5));
+        assert_eq!(result.err().unwrap(), MintRateLimitExceeded.into());
+        cep18_token.mint(&user, U256::from(4));
+        assert_eq!(
+            cep18_token.mint_window(&minter_1).unwrap().used,
+            U256::from(10)
+        );
+        // the capacity refills gradually rather than all at once
+        env.advance_block_time(day / 2);
+        let result = cep18_token.try_mint(&user, U256::from(6));
+        assert_eq!(result.err().unwrap(), MintRateLimitExceeded.into());
+        cep18_token.mint(&user, U256::from(5));
+        env.advance_block_time(day);
+        cep18_token.mint(&user, U256::from(10));
+        let result = cep18_token.try_mint(&user, U256::one());
+        assert_eq!(result.err().unwrap(), MintRateLimitExceeded.into());
+        assert_eq!(cep18_token.minter_allowance(&minter_1), U256::from(75));
+        // reconfiguring keeps the undrained part of the bucket, up to the new limit
+        env.set_caller(controller_1);
+        cep18_token.configure_minter_rate_limit(&minter_1, day, U256::from(20));
+        assert_eq!(
+            cep18_token.mint_window(&minter_1).unwrap().used,
+            U256::from(10)
+        );
+        cep18_token.configure_minter_rate_limit(&minter_1, day, U256::from(4));
+        assert_eq!(
+            cep18_token.mint_window(&minter_1).unwrap().used,
+            U256::from(4)
+        );
+        env.set_caller(minter_1);
+        let result = cep18_token.try_mint(&user, U256::one());
+        assert_eq!(result.err().unwrap(), MintRateLimitExceeded.into());
+        env.advance_block_time(day);
+        cep18_token.mint(&user, U256::from(4));
+        assert_eq!(cep18_token.minter_allowance(&minter_1), U256::from(71));
+        // the limit can be lifted
+        env.set_caller(controller_1);
+        cep18_token.configure_minter_rate_limit(&minter_1, 0, U256::zero());
+        env.set_caller(minter_1);
+        cep18_token.mint(&user, U256::from(71));
+        assert!(cep18_token.mint_window(&minter_1).is_none());
+    }
+
+    #[test]
+    fn test_max_supply() {
+        let env = odra_test::env();
+        let (master_minter, controller_1, minter_1) =
+            (env.get_account(1), env.get_account(2), env.get_account(3));
+        let (user, owner) = (env.get_account(6), env.get_account(7));
+        let mut cep18_token = setup_with(&env, vec![owner], StablecoinModality::MintAndBurn);
+        assert_eq!(cep18_token.max_supply(), U256::MAX);
+        env.set_caller(master_minter);
+        cep18_token.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        cep18_token.configure_minter_allowance(&minter_1, U256::from(100));
+
+        env.set_caller(owner);
+        let result = cep18_token.try_set_max_supply((TOKEN_TOTAL_SUPPLY - 1).into());
+        assert_eq!(result.err().unwrap(), SupplyCapExceeded.into());
+        cep18_token.set_max_supply((TOKEN_TOTAL_SUPPLY + 10).into());
+        assert!(
+            env.emitted(&cep18_token, "MaxSupplyChanged"),
+            "MaxSupplyChanged event not emitted"
+        );
+        assert_eq!(cep18_token.max_supply(), (TOKEN_TOTAL_SUPPLY + 10).into());
+        env.set_caller(minter_1);
+        let result = cep18_token.try_mint(&user, U256::from(11));
+        assert_eq!(result.err().unwrap(), SupplyCapExceeded.into());
+        cep18_token.mint(&user, U256::from(10));
+        assert_eq!(cep18_token.total_supply(), cep18_token.max_supply());
+    }
 }
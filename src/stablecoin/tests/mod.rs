@@ -1,5 +1,14 @@
 mod allowance;
+mod allowlist;
+mod blacklist;
 mod client_contract_test;
 mod mint_and_burn;
 mod permissions;
+mod proposals;
+mod receiver_contract_test;
+mod redemptions;
+mod rescue;
+mod roles;
+mod signatures;
+mod timelock;
 mod transfer;
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod test_permissions {
-    use crate::stablecoin::utils::StablecoinModality;
+    use crate::stablecoin::errors::Error::{
+        ContractIsPaused, MinterAlreadyControlled, MinterNotControlled,
+    };
+    use crate::stablecoin::utils::{PausableOperation, StablecoinModality};
     use crate::stablecoin::setup_tests::{
         setup_with_args, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     };
@@ -62,7 +65,7 @@ mod test_permissions {
             "ControllerConfigured event not emitted"
         );
         env.set_caller(controller_1);
-        stablecoin.configure_minter_allowance(U256::from(10));
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
         assert!(
             env.emitted(&stablecoin, "MinterConfigured"),
             "MinterConfigured event not emitted"
@@ -87,7 +90,7 @@ mod test_permissions {
         }
         // remove the minter
         env.set_caller(controller_1);
-        stablecoin.remove_minter();
+        stablecoin.remove_minter(&minter_1);
         // try to mint with disabled minter
         env.set_caller(minter_1);
         let result: Result<(), odra::OdraError> = stablecoin.try_mint(&user, U256::from(10));
@@ -105,19 +108,109 @@ mod test_permissions {
         env.set_caller(master_minter);
         stablecoin.configure_controller(&controller_1, &minter_1);
         env.set_caller(controller_1);
-        stablecoin.remove_minter();
+        stablecoin.remove_minter(&minter_1);
         assert!(
             env.emitted(&stablecoin, "MinterRemoved"),
             "MinterRemoved event not emitted"
         );
         env.set_caller(master_minter);
-        stablecoin.remove_controller(&controller_1);
+        stablecoin.remove_controller(&controller_1, false);
         assert!(
             env.emitted(&stablecoin, "ControllerRemoved"),
             "ControllerRemoved event not emitted"
         );
     }
 
+    #[test]
+    fn test_controller_manages_many_minters() {
+        let (env, master_minter, controller_1, minter_1, .., user, mut stablecoin) = setup();
+        let controller_2 = env.get_account(8);
+        let minter_2 = env.get_account(9);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_1, &minter_1);
+        stablecoin.configure_controller(&controller_1, &minter_2);
+        stablecoin.configure_controller(&controller_2, &user);
+        assert_eq!(
+            stablecoin.minters_of(&controller_1),
+            vec![minter_1, minter_2]
+        );
+        assert_eq!(stablecoin.controller_of(&minter_2), Some(controller_1));
+        assert_eq!(stablecoin.controller_of(&controller_1), None);
+        env.set_caller(controller_1);
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
+        stablecoin.configure_minter_allowance(&minter_2, U256::from(20));
+        stablecoin.decrease_minter_allowance(&minter_2, U256::from(5));
+        assert_eq!(stablecoin.minter_allowance(&minter_1), U256::from(10));
+        assert_eq!(stablecoin.minter_allowance(&minter_2), U256::from(15));
+        // a controller cannot touch the minters of another controller
+        let result = stablecoin.try_configure_minter_allowance(&user, U256::from(10));
+        assert_eq!(result.err().unwrap(), MinterNotControlled.into());
+        let result = stablecoin.try_remove_minter(&user);
+        assert_eq!(result.err().unwrap(), MinterNotControlled.into());
+        stablecoin.remove_minter(&minter_1);
+        assert!(!stablecoin.is_minter(&minter_1));
+        assert!(stablecoin.is_minter(&minter_2));
+        // the removed minter is fully unwound and can be paired again
+        assert_eq!(stablecoin.minter_allowance(&minter_1), U256::zero());
+        assert_eq!(stablecoin.controller_of(&minter_1), None);
+        assert_eq!(stablecoin.minters_of(&controller_1), vec![minter_2]);
+        let result = stablecoin.try_configure_minter_allowance(&minter_1, U256::from(10));
+        assert_eq!(result.err().unwrap(), MinterNotControlled.into());
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_2, &minter_1);
+        assert_eq!(stablecoin.controller_of(&minter_1), Some(controller_2));
+    }
+
+    #[test]
+    fn test_remove_controller_unwinds_pairing() {
+        let (env, master_minter, controller_1, minter_1, .., user, mut stablecoin) = setup();
+        let controller_2 = env.get_account(8);
+        let minter_2 = env.get_account(9);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_1, &minter_1);
+        stablecoin.configure_controller(&controller_1, &minter_2);
+        let result = stablecoin.try_configure_controller(&controller_2, &minter_1);
+        assert_eq!(result.err().unwrap(), MinterAlreadyControlled.into());
+        env.set_caller(controller_1);
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
+        stablecoin.configure_minter_allowance(&minter_2, U256::from(10));
+
+        env.set_caller(master_minter);
+        stablecoin.remove_controller(&controller_1, true);
+        assert_eq!(stablecoin.minters_of(&controller_1), vec![]);
+        assert_eq!(stablecoin.controller_of(&minter_1), None);
+        assert!(!stablecoin.is_minter(&minter_1));
+        assert_eq!(stablecoin.minter_allowance(&minter_2), U256::zero());
+        env.set_caller(minter_1);
+        let result = stablecoin.try_mint(&user, U256::from(1));
+        assert!(result.is_err());
+
+        // unpaired minters can be paired again
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_2, &minter_1);
+        assert_eq!(stablecoin.controller_of(&minter_1), Some(controller_2));
+    }
+
+    #[test]
+    fn test_remove_controller_keeping_minters_clears_allowances() {
+        let (env, master_minter, controller_1, minter_1, .., user, mut stablecoin) = setup();
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
+        stablecoin.configure_minter_rate_limit(&minter_1, 1_000, U256::from(5));
+
+        env.set_caller(master_minter);
+        stablecoin.remove_controller(&controller_1, false);
+        assert!(stablecoin.is_minter(&minter_1));
+        assert_eq!(stablecoin.controller_of(&minter_1), None);
+        assert_eq!(stablecoin.minter_allowance(&minter_1), U256::zero());
+        assert!(stablecoin.mint_window(&minter_1).is_none());
+        env.set_caller(minter_1);
+        let result = stablecoin.try_mint(&user, U256::from(1));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn must_not_mint_when_paused() {
         let (env, master_minter, controller_1, minter_1, .., pauser, user, mut stablecoin) =
@@ -125,7 +218,7 @@ mod test_permissions {
         env.set_caller(master_minter);
         stablecoin.configure_controller(&controller_1, &minter_1);
         env.set_caller(controller_1);
-        stablecoin.configure_minter_allowance(U256::from(10));
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
         env.set_caller(pauser);
         stablecoin.pause();
         env.set_caller(minter_1);
@@ -137,4 +230,44 @@ mod test_permissions {
             _ => {}
         }
     }
+
+    #[test]
+    fn test_pause_single_operation() {
+        let (env, master_minter, controller_1, minter_1, .., pauser, user, mut stablecoin) =
+            setup();
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(10));
+        env.set_caller(pauser);
+        stablecoin.pause_operation(PausableOperation::Mint);
+        assert!(
+            env.emitted(&stablecoin, "OperationPaused"),
+            "OperationPaused event not emitted"
+        );
+        assert!(stablecoin.pause_state().mint);
+        assert!(!stablecoin.pause_state().transfer);
+        env.set_caller(minter_1);
+        let result = stablecoin.try_mint(&minter_1, U256::from(10));
+        assert_eq!(result.err().unwrap(), ContractIsPaused.into());
+
+        // transfers keep working while minting is halted
+        env.set_caller(env.get_account(0));
+        stablecoin.transfer(&user, &U256::one());
+        env.set_caller(pauser);
+        stablecoin.pause_operation(PausableOperation::Approve);
+        env.set_caller(user);
+        let result = stablecoin.try_increase_allowance(&minter_1, &U256::one());
+        assert_eq!(result.err().unwrap(), ContractIsPaused.into());
+
+        env.set_caller(pauser);
+        stablecoin.unpause_operation(PausableOperation::Mint);
+        assert!(
+            env.emitted(&stablecoin, "OperationUnpaused"),
+            "OperationUnpaused event not emitted"
+        );
+        env.set_caller(minter_1);
+        stablecoin.mint(&minter_1, U256::from(10));
+        assert!(stablecoin.pause_state().approve);
+    }
 }
@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod proposals_tests {
+    use crate::stablecoin::errors::Error::{
+        CannotRemoveLastOwner, InsufficientRights, InvalidAction, InvalidThreshold,
+        MissingProposal, MissingRole, ProposalAlreadyApproved, ProposalExpired, ProposalRequired,
+        ReservedRole,
+    };
+    use crate::stablecoin::setup_tests::{setup_with, TOKEN_TOTAL_SUPPLY};
+    use crate::stablecoin::storage::Roles;
+    use crate::stablecoin::utils::{AdminAction, ProposalStatus, StablecoinModality};
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::casper_types::U256;
+    use odra::host::HostEnv;
+    use odra::Address;
+
+    const LIFETIME: u64 = 60_000;
+
+    fn setup() -> (HostEnv, Address, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let owner_1 = env.get_account(7);
+        let owner_2 = env.get_account(8);
+        let owner_3 = env.get_account(9);
+        let stablecoin = setup_with(
+            &env,
+            vec![owner_1, owner_2, owner_3],
+            StablecoinModality::MintAndBurn,
+        );
+        (env, owner_1, owner_2, owner_3, stablecoin)
+    }
+
+    fn require_two_approvals(env: &HostEnv, stablecoin: &mut StablecoinHostRef, owner: Address) {
+        env.set_caller(owner);
+        stablecoin.propose(AdminAction::SetProposalThreshold { threshold: 2 }, LIFETIME);
+        assert_eq!(stablecoin.proposal_threshold(), 2);
+    }
+
+    #[test]
+    fn test_single_approval_executes_immediately() {
+        let (env, owner_1, .., mut stablecoin) = setup();
+        let user = env.get_account(6);
+        env.set_caller(owner_1);
+        let id = stablecoin.propose(AdminAction::Blacklist { account: user }, LIFETIME);
+        assert!(stablecoin.is_blacklisted(&user));
+        assert_eq!(
+            stablecoin.proposal(id).unwrap().status,
+            ProposalStatus::Executed
+        );
+        assert!(
+            env.emitted(&stablecoin, "ProposalExecuted"),
+            "ProposalExecuted event not emitted"
+        );
+    }
+
+    #[test]
+    fn test_action_runs_once_threshold_is_reached() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        let controller = env.get_account(2);
+        let minter = env.get_account(3);
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let id = stablecoin.propose(
+            AdminAction::ConfigureController { controller, minter },
+            LIFETIME,
+        );
+        assert!(!stablecoin.is_minter(&minter));
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), ProposalAlreadyApproved.into());
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(id);
+        assert!(
+            env.emitted(&stablecoin, "ProposalApproved"),
+            "ProposalApproved event not emitted"
+        );
+        assert!(stablecoin.is_minter(&minter));
+        let proposal = stablecoin.proposal(id).unwrap();
+        assert_eq!(proposal.approvals, 2);
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), MissingProposal.into());
+    }
+
+    #[test]
+    fn test_only_owners_take_part() {
+        let (env, owner_1, .., mut stablecoin) = setup();
+        let user = env.get_account(6);
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let id = stablecoin.propose(AdminAction::Pause, LIFETIME);
+        env.set_caller(user);
+        let result = stablecoin.try_propose(AdminAction::Pause, LIFETIME);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+    }
+
+    #[test]
+    fn test_expired_proposal_cannot_be_approved() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let id = stablecoin.propose(AdminAction::Pause, LIFETIME);
+        env.advance_block_time(LIFETIME + 1);
+        env.set_caller(owner_2);
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), ProposalExpired.into());
+    }
+
+    #[test]
+    fn test_cancel_proposal() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let id = stablecoin.propose(AdminAction::Pause, LIFETIME);
+        env.set_caller(owner_2);
+        let result = stablecoin.try_cancel_proposal(id);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(owner_1);
+        stablecoin.cancel_proposal(id);
+        assert!(
+            env.emitted(&stablecoin, "ProposalCancelled"),
+            "ProposalCancelled event not emitted"
+        );
+        env.set_caller(owner_2);
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), MissingProposal.into());
+        assert_eq!(
+            stablecoin.proposal(id).unwrap().status,
+            ProposalStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_threshold_stays_reachable() {
+        let (env, owner_1, owner_2, owner_3, mut stablecoin) = setup();
+        env.set_caller(owner_1);
+        let result =
+            stablecoin.try_propose(AdminAction::SetProposalThreshold { threshold: 4 }, LIFETIME);
+        assert_eq!(result.err().unwrap(), InvalidThreshold.into());
+        stablecoin.propose(AdminAction::SetProposalThreshold { threshold: 3 }, LIFETIME);
+        let id = stablecoin.propose(AdminAction::RemoveOwner { account: owner_3 }, LIFETIME);
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(id);
+        env.set_caller(owner_3);
+        let result = stablecoin.try_approve_proposal(id);
+        assert_eq!(result.err().unwrap(), InvalidThreshold.into());
+
+        // with a threshold of one, the last Owner is still protected
+        let (env, owner_1, owner_2, owner_3, mut stablecoin) = setup();
+        env.set_caller(owner_1);
+        stablecoin.propose(AdminAction::RemoveOwner { account: owner_2 }, LIFETIME);
+        stablecoin.propose(AdminAction::RemoveOwner { account: owner_3 }, LIFETIME);
+        let result =
+            stablecoin.try_propose(AdminAction::RemoveOwner { account: owner_1 }, LIFETIME);
+        assert_eq!(result.err().unwrap(), CannotRemoveLastOwner.into());
+    }
+
+    #[test]
+    fn test_owner_entrypoints_need_proposal_above_threshold() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        let user = env.get_account(6);
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let result = stablecoin.try_add_owner(&user);
+        assert_eq!(result.err().unwrap(), ProposalRequired.into());
+        let result = stablecoin.try_remove_owner(&owner_2);
+        assert_eq!(result.err().unwrap(), ProposalRequired.into());
+        let result = stablecoin.try_set_max_supply(U256::one());
+        assert_eq!(result.err().unwrap(), ProposalRequired.into());
+        assert!(!stablecoin.is_owner(&user));
+
+        let id = stablecoin.propose(AdminAction::AddOwner { account: user }, LIFETIME);
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(id);
+        assert!(stablecoin.is_owner(&user));
+    }
+
+    #[test]
+    fn test_removed_owner_approval_does_not_count() {
+        let (env, owner_1, owner_2, owner_3, mut stablecoin) = setup();
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let pause = stablecoin.propose(AdminAction::Pause, LIFETIME);
+        env.set_caller(owner_2);
+        let removal = stablecoin.propose(AdminAction::RemoveOwner { account: owner_1 }, LIFETIME);
+        env.set_caller(owner_3);
+        stablecoin.approve_proposal(removal);
+        assert!(!stablecoin.is_owner(&owner_1));
+
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(pause);
+        let proposal = stablecoin.proposal(pause).unwrap();
+        assert_eq!(proposal.approvals, 1);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+        env.set_caller(owner_3);
+        stablecoin.approve_proposal(pause);
+        let proposal = stablecoin.proposal(pause).unwrap();
+        assert_eq!(proposal.approvals, 2);
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_proposal_lifetime_saturates() {
+        let (env, owner_1, .., mut stablecoin) = setup();
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        env.advance_block_time(1);
+        let id = stablecoin.propose(AdminAction::Pause, u64::MAX);
+        assert_eq!(stablecoin.proposal(id).unwrap().expires_at, u64::MAX);
+    }
+
+    #[test]
+    fn test_proposals_cannot_bypass_role_entrypoints() {
+        let (env, owner_1, owner_2, owner_3, mut stablecoin) = setup();
+        let user = env.get_account(6);
+        env.set_caller(owner_1);
+        stablecoin.propose(AdminAction::RemoveOwner { account: owner_2 }, LIFETIME);
+        stablecoin.propose(AdminAction::RemoveOwner { account: owner_3 }, LIFETIME);
+        let result = stablecoin.try_propose(
+            AdminAction::RevokeRole {
+                role: Roles::Owner,
+                account: owner_1,
+            },
+            LIFETIME,
+        );
+        assert_eq!(result.err().unwrap(), ReservedRole.into());
+        let result = stablecoin.try_propose(
+            AdminAction::GrantRole {
+                role: Roles::Minter,
+                account: user,
+            },
+            LIFETIME,
+        );
+        assert_eq!(result.err().unwrap(), ReservedRole.into());
+        let result = stablecoin.try_propose(
+            AdminAction::SetRoleAdmin {
+                role: Roles::Blacklisted,
+                admin_role: Roles::Pauser,
+            },
+            LIFETIME,
+        );
+        assert_eq!(result.err().unwrap(), ReservedRole.into());
+        assert!(stablecoin.is_owner(&owner_1));
+        assert!(!stablecoin.is_minter(&user));
+    }
+
+    #[test]
+    fn test_handover_cannot_be_proposed() {
+        let (env, owner_1, .., mut stablecoin) = setup();
+        let (minter, pauser, user) = (env.get_account(3), env.get_account(5), env.get_account(6));
+        env.set_caller(owner_1);
+        let result = stablecoin.try_propose(
+            AdminAction::HandOverRole {
+                role: Roles::Pauser,
+                previous_holder: pauser,
+                new_holder: user,
+            },
+            LIFETIME,
+        );
+        assert_eq!(result.err().unwrap(), InvalidAction.into());
+        assert!(!stablecoin.is_pausers(&user));
+
+        let result = stablecoin.try_propose(
+            AdminAction::UpdatePauser {
+                old_pauser: minter,
+                new_pauser: user,
+            },
+            LIFETIME,
+        );
+        assert_eq!(result.err().unwrap(), MissingRole.into());
+        // a proposed replacement still waits for the nominee to accept
+        stablecoin.propose(
+            AdminAction::UpdatePauser {
+                old_pauser: pauser,
+                new_pauser: user,
+            },
+            LIFETIME,
+        );
+        assert!(stablecoin.is_pausers(&pauser));
+        env.set_caller(user);
+        stablecoin.accept_role(Roles::Pauser);
+        assert!(stablecoin.is_pausers(&user));
+        assert!(!stablecoin.is_pausers(&pauser));
+    }
+
+    #[test]
+    fn test_rotate_master_minter_through_proposal() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        let (master_minter, user) = (env.get_account(1), env.get_account(6));
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        let id = stablecoin.propose(
+            AdminAction::UpdateMasterMinter {
+                old_master_minter: master_minter,
+                new_master_minter: user,
+            },
+            LIFETIME,
+        );
+        assert!(stablecoin
+            .pending_role_transfer(Roles::MasterMinter, &user)
+            .is_none());
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(id);
+        env.set_caller(user);
+        stablecoin.accept_role(Roles::MasterMinter);
+        assert!(stablecoin.is_master_minter(&user));
+        assert!(!stablecoin.is_master_minter(&master_minter));
+    }
+
+    #[test]
+    fn test_owner_seizes_through_proposal() {
+        let (env, owner_1, owner_2, _, mut stablecoin) = setup();
+        let (holder, blacklister, recovery) =
+            (env.get_account(0), env.get_account(4), env.get_account(6));
+        require_two_approvals(&env, &mut stablecoin, owner_1);
+        env.set_caller(blacklister);
+        stablecoin.blacklist(&holder);
+        env.set_caller(owner_1);
+        let result = stablecoin.try_seize(&holder, Some(recovery));
+        assert_eq!(result.err().unwrap(), ProposalRequired.into());
+        let id = stablecoin.propose(
+            AdminAction::Seize {
+                account: holder,
+                recovery: Some(recovery),
+            },
+            LIFETIME,
+        );
+        assert_eq!(stablecoin.balance_of(&recovery), U256::zero());
+        env.set_caller(owner_2);
+        stablecoin.approve_proposal(id);
+        assert_eq!(stablecoin.balance_of(&holder), U256::zero());
+        assert_eq!(stablecoin.balance_of(&recovery), TOKEN_TOTAL_SUPPLY.into());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::stablecoin::StablecoinContractRef;
+use odra::casper_types::bytesrepr::Bytes;
+use odra::casper_types::U256;
+use odra::prelude::*;
+use odra::{Address, Var};
+
+/// An example contract that is notified of tokens sent to it with `transfer_and_call`
+/// and of allowances granted to it with `approve_and_call`.
+/// Only for purpose of testing the receiver hooks of the Stablecoin module.
+#[odra::module]
+pub struct StablecoinReceiverContract {
+    accept: Var<bool>,
+    received: Var<U256>,
+    last_from: Var<Address>,
+    last_data: Var<Bytes>,
+}
+
+#[odra::module]
+impl StablecoinReceiverContract {
+    /// Initializes the receiver, which accepts or rejects every transfer.
+    #[allow(dead_code)]
+    pub fn init(&mut self, accept: bool) {
+        self.accept.set(accept);
+    }
+
+    /// Records the received tokens if the receiver accepts them.
+    #[allow(dead_code, unused_variables)]
+    pub fn on_stablecoin_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> bool {
+        let accept = self.accept.get_or_default();
+        if accept {
+            self.received.set(self.received.get_or_default() + amount);
+            self.last_from.set(from);
+            self.last_data.set(data);
+        }
+        accept
+    }
+
+    /// Pulls the approved tokens from the owner if the receiver accepts them.
+    #[allow(dead_code)]
+    pub fn on_approval_received(&mut self, owner: Address, amount: U256, data: Bytes) -> bool {
+        let accept = self.accept.get_or_default();
+        if accept {
+            let mut token_contract = StablecoinContractRef::new(self.env(), self.env().caller());
+            token_contract.transfer_from(&owner, &self.env().self_address(), &amount);
+            self.received.set(self.received.get_or_default() + amount);
+            self.last_from.set(owner);
+            self.last_data.set(data);
+        }
+        accept
+    }
+
+    /// Returns the total amount of tokens received.
+    #[allow(dead_code)]
+    pub fn received(&self) -> U256 {
+        self.received.get_or_default()
+    }
+
+    /// Returns the sender of the last accepted transfer.
+    #[allow(dead_code)]
+    pub fn last_from(&self) -> Option<Address> {
+        self.last_from.get()
+    }
+
+    /// Returns the data attached to the last accepted transfer.
+    #[allow(dead_code)]
+    pub fn last_data(&self) -> Bytes {
+        self.last_data.get_or_default()
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod redemptions_tests {
+    use crate::stablecoin::errors::Error::{InsufficientRights, MissingRedemption};
+    use crate::stablecoin::setup_tests::setup;
+    use crate::stablecoin::utils::RedemptionStatus;
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::string::ToString;
+    use odra::casper_types::U256;
+    use odra::host::{HostEnv, HostRef};
+    use odra::Address;
+
+    fn setup_holder() -> (HostEnv, Address, Address, StablecoinHostRef) {
+        let (env, master_minter, controller_1, minter_1, .., user, mut stablecoin) = setup();
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller_1, &minter_1);
+        env.set_caller(controller_1);
+        stablecoin.configure_minter_allowance(&minter_1, U256::from(100));
+        env.set_caller(minter_1);
+        stablecoin.mint(&user, U256::from(100));
+        (env, minter_1, user, stablecoin)
+    }
+
+    #[test]
+    fn test_finalize_redemption() {
+        let (env, minter, user, mut stablecoin) = setup_holder();
+        let total_supply = stablecoin.total_supply();
+        env.set_caller(user);
+        let id = stablecoin.request_redemption(U256::from(40), "wire-0001".to_string());
+        assert!(
+            env.emitted(&stablecoin, "RedemptionRequested"),
+            "RedemptionRequested event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&user), U256::from(60));
+        assert_eq!(stablecoin.balance_of(stablecoin.address()), U256::from(40));
+        assert_eq!(stablecoin.escrowed_redemptions(), U256::from(40));
+        let result = stablecoin.try_finalize_redemption(id);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+
+        env.set_caller(minter);
+        stablecoin.finalize_redemption(id);
+        assert!(
+            env.emitted(&stablecoin, "RedemptionFinalized"),
+            "RedemptionFinalized event not emitted"
+        );
+        assert_eq!(stablecoin.total_supply(), total_supply - 40);
+        assert_eq!(stablecoin.escrowed_redemptions(), U256::zero());
+        let redemption = stablecoin.redemption(id).unwrap();
+        assert_eq!(redemption.status, RedemptionStatus::Finalized);
+        assert_eq!(redemption.reference, "wire-0001");
+        let result = stablecoin.try_reject_redemption(id);
+        assert_eq!(result.err().unwrap(), MissingRedemption.into());
+    }
+
+    #[test]
+    fn test_reject_redemption() {
+        let (env, minter, user, mut stablecoin) = setup_holder();
+        env.set_caller(user);
+        stablecoin.request_redemption(U256::from(30), "wire-0001".to_string());
+        let id = stablecoin.request_redemption(U256::from(20), "wire-0002".to_string());
+        assert_eq!(id, 1);
+        env.set_caller(minter);
+        stablecoin.reject_redemption(id);
+        assert!(
+            env.emitted(&stablecoin, "RedemptionRejected"),
+            "RedemptionRejected event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&user), U256::from(70));
+        assert_eq!(stablecoin.escrowed_redemptions(), U256::from(30));
+        assert_eq!(
+            stablecoin.redemption(id).unwrap().status,
+            RedemptionStatus::Rejected
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod rescue_tests {
+    use crate::stablecoin::errors::Error::{InsufficientRescuableBalance, InsufficientRights};
+    use crate::stablecoin::setup_tests::setup_with;
+    use crate::stablecoin::storage::Roles;
+    use crate::stablecoin::utils::StablecoinModality;
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::casper_types::U256;
+    use odra::host::{HostEnv, HostRef};
+    use odra::Address;
+
+    fn deploy(env: &HostEnv, owner: Address) -> StablecoinHostRef {
+        setup_with(env, vec![owner], StablecoinModality::MintAndBurn)
+    }
+
+    fn setup() -> (HostEnv, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let owner = env.get_account(7);
+        let rescuer = env.get_account(8);
+        // the deployer holds the initial supply
+        let holder = env.get_account(0);
+        let mut stablecoin = deploy(&env, owner);
+        env.set_caller(owner);
+        stablecoin.grant_role(Roles::Rescuer, &rescuer);
+        env.set_caller(holder);
+        (env, rescuer, holder, stablecoin)
+    }
+
+    #[test]
+    fn test_rescue_own_tokens() {
+        let (env, rescuer, _, mut stablecoin) = setup();
+        let recipient = env.get_account(6);
+        let self_address = *stablecoin.address();
+        stablecoin.transfer(&self_address, &U256::from(100));
+        stablecoin.request_redemption(U256::from(40), "ref".to_string());
+
+        let result = stablecoin.try_rescue_tokens(&self_address, &recipient, U256::from(100));
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+
+        // the redemption escrow cannot be rescued
+        env.set_caller(rescuer);
+        let result = stablecoin.try_rescue_tokens(&self_address, &recipient, U256::from(101));
+        assert_eq!(result.err().unwrap(), InsufficientRescuableBalance.into());
+        stablecoin.rescue_tokens(&self_address, &recipient, U256::from(100));
+        assert!(
+            env.emitted(&stablecoin, "TokensRescued"),
+            "TokensRescued event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&recipient), U256::from(100));
+        assert_eq!(stablecoin.balance_of(&self_address), U256::from(40));
+        assert_eq!(stablecoin.escrowed_redemptions(), U256::from(40));
+    }
+
+    #[test]
+    fn test_rescue_foreign_tokens() {
+        let (env, rescuer, holder, mut stablecoin) = setup();
+        let recipient = env.get_account(6);
+        let mut foreign = deploy(&env, env.get_account(7));
+        env.set_caller(holder);
+        foreign.transfer(stablecoin.address(), &U256::from(100));
+
+        env.set_caller(rescuer);
+        stablecoin.rescue_tokens(foreign.address(), &recipient, U256::from(60));
+        assert_eq!(foreign.balance_of(&recipient), U256::from(60));
+        assert_eq!(foreign.balance_of(stablecoin.address()), U256::from(40));
+        assert_eq!(stablecoin.balance_of(&recipient), U256::zero());
+
+        // the role is revoked like any other custom role
+        env.set_caller(env.get_account(7));
+        stablecoin.revoke_role(Roles::Rescuer, &rescuer);
+        env.set_caller(rescuer);
+        let result = stablecoin.try_rescue_tokens(foreign.address(), &recipient, U256::from(40));
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+    }
+}
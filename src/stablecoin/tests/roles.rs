@@ -0,0 +1,319 @@
+#[cfg(test)]
+mod roles_tests {
+    use crate::stablecoin::errors::Error::{
+        CannotRemoveLastOwner, InsufficientRights, MissingRole, MissingRoleTransfer, ReservedRole,
+        RoleTransferExpired, RoleTransferPending,
+    };
+    use crate::stablecoin::setup_tests::setup_with;
+    use crate::stablecoin::storage::Roles;
+    use crate::stablecoin::utils::{StablecoinModality, ROLE_TRANSFER_LIFETIME};
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::host::HostEnv;
+    use odra::Address;
+
+    fn setup() -> (HostEnv, Address, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let master_minter = env.get_account(1);
+        let pauser = env.get_account(5);
+        let owner = env.get_account(7);
+        let stablecoin = setup_with(&env, vec![owner], StablecoinModality::MintAndBurn);
+        (env, master_minter, pauser, owner, stablecoin)
+    }
+
+    #[test]
+    fn test_add_and_remove_owner() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let new_owner = env.get_account(8);
+        env.set_caller(owner);
+        stablecoin.add_owner(&new_owner);
+        assert!(stablecoin.is_owner(&new_owner));
+        assert!(
+            env.emitted(&stablecoin, "RoleConfigured"),
+            "RoleConfigured event not emitted"
+        );
+        env.set_caller(new_owner);
+        stablecoin.remove_owner(&owner);
+        assert!(!stablecoin.is_owner(&owner));
+        assert!(
+            env.emitted(&stablecoin, "RoleRevoked"),
+            "RoleRevoked event not emitted"
+        );
+    }
+
+    #[test]
+    fn test_last_owner_cannot_be_removed() {
+        let (env, .., owner, mut stablecoin) = setup();
+        env.set_caller(owner);
+        let result = stablecoin.try_remove_owner(&owner);
+        assert_eq!(result.err().unwrap(), CannotRemoveLastOwner.into());
+        assert!(stablecoin.is_owner(&owner));
+    }
+
+    #[test]
+    fn test_only_owner_can_administer_roles() {
+        let (env, master_minter, pauser, _, mut stablecoin) = setup();
+        let user = env.get_account(6);
+        env.set_caller(master_minter);
+        let result = stablecoin.try_add_owner(&user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        let result = stablecoin.try_update_master_minter(&master_minter, &user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(pauser);
+        let result = stablecoin.try_update_pauser(&pauser, &user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+    }
+
+    #[test]
+    fn test_rotate_master_minter_and_pauser() {
+        let (env, master_minter, pauser, owner, mut stablecoin) = setup();
+        let new_master_minter = env.get_account(8);
+        let new_pauser = env.get_account(9);
+        env.set_caller(owner);
+        let result = stablecoin.try_update_master_minter(&new_pauser, &new_master_minter);
+        assert_eq!(result.err().unwrap(), MissingRole.into());
+        stablecoin.update_master_minter(&master_minter, &new_master_minter);
+        stablecoin.update_pauser(&pauser, &new_pauser);
+        assert!(
+            env.emitted(&stablecoin, "RoleTransferProposed"),
+            "RoleTransferProposed event not emitted"
+        );
+        // nothing changes before the nominees accept
+        assert!(stablecoin.is_master_minter(&master_minter));
+        assert!(stablecoin.is_pausers(&pauser));
+        env.set_caller(new_master_minter);
+        stablecoin.accept_role(Roles::MasterMinter);
+        env.set_caller(new_pauser);
+        stablecoin.accept_role(Roles::Pauser);
+        assert!(
+            env.emitted(&stablecoin, "RoleTransferAccepted"),
+            "RoleTransferAccepted event not emitted"
+        );
+        assert!(!stablecoin.is_master_minter(&master_minter));
+        assert!(stablecoin.is_master_minter(&new_master_minter));
+        assert!(!stablecoin.is_pausers(&pauser));
+        assert!(stablecoin.is_pausers(&new_pauser));
+
+        // the old keys lost their powers, the new ones can act
+        let controller = env.get_account(2);
+        let minter = env.get_account(3);
+        env.set_caller(master_minter);
+        let result = stablecoin.try_configure_controller(&controller, &minter);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(new_master_minter);
+        stablecoin.configure_controller(&controller, &minter);
+        env.set_caller(pauser);
+        assert_eq!(
+            stablecoin.try_pause().err().unwrap(),
+            InsufficientRights.into()
+        );
+        env.set_caller(new_pauser);
+        stablecoin.pause();
+    }
+
+    #[test]
+    fn test_hand_over_blacklister() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let blacklister = env.get_account(4);
+        let new_blacklister = env.get_account(8);
+        let user = env.get_account(6);
+        env.set_caller(owner);
+        stablecoin.update_blacklister(&new_blacklister);
+        // only the nominee can accept
+        env.set_caller(user);
+        let result = stablecoin.try_accept_role(Roles::Blacklister);
+        assert_eq!(result.err().unwrap(), MissingRoleTransfer.into());
+        env.set_caller(new_blacklister);
+        stablecoin.accept_role(Roles::Blacklister);
+        assert!(
+            env.emitted(&stablecoin, "BlacklisterChanged"),
+            "BlacklisterChanged event not emitted"
+        );
+        stablecoin.blacklist(&user);
+        assert!(stablecoin.is_blacklisted(&user));
+        env.set_caller(blacklister);
+        let result = stablecoin.try_unblacklist(&user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+    }
+
+    #[test]
+    fn test_superseded_role_transfer_cannot_duplicate_role() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let first_nominee = env.get_account(8);
+        let second_nominee = env.get_account(9);
+        env.set_caller(owner);
+        stablecoin.update_blacklister(&first_nominee);
+        stablecoin.update_blacklister(&second_nominee);
+        env.set_caller(first_nominee);
+        stablecoin.accept_role(Roles::Blacklister);
+        // the previous Blacklister no longer holds the role
+        env.set_caller(second_nominee);
+        let result = stablecoin.try_accept_role(Roles::Blacklister);
+        assert_eq!(result.err().unwrap(), MissingRole.into());
+        assert!(stablecoin.has_role(Roles::Blacklister, &first_nominee));
+        assert!(!stablecoin.has_role(Roles::Blacklister, &second_nominee));
+        assert_eq!(stablecoin.role_member_count(Roles::Blacklister), 1);
+    }
+
+    #[test]
+    fn test_cancel_role_transfer() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let nominee = env.get_account(8);
+        env.set_caller(owner);
+        stablecoin.transfer_role(Roles::Owner, &nominee);
+        assert!(stablecoin
+            .pending_role_transfer(Roles::Owner, &nominee)
+            .is_some());
+        let result = stablecoin.try_transfer_role(Roles::Owner, &nominee);
+        assert_eq!(result.err().unwrap(), RoleTransferPending.into());
+        // only the proposer or an Owner can cancel
+        env.set_caller(nominee);
+        let result = stablecoin.try_cancel_role_transfer(Roles::Owner, &nominee);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(owner);
+        stablecoin.cancel_role_transfer(Roles::Owner, &nominee);
+        assert!(
+            env.emitted(&stablecoin, "RoleTransferCancelled"),
+            "RoleTransferCancelled event not emitted"
+        );
+        env.set_caller(nominee);
+        let result = stablecoin.try_accept_role(Roles::Owner);
+        assert_eq!(result.err().unwrap(), MissingRoleTransfer.into());
+        assert!(stablecoin.is_owner(&owner));
+        assert!(!stablecoin.is_owner(&nominee));
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let nominee = env.get_account(8);
+        env.set_caller(owner);
+        let result = stablecoin.try_transfer_role(Roles::Minter, &nominee);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        stablecoin.transfer_role(Roles::Owner, &nominee);
+        env.set_caller(nominee);
+        stablecoin.accept_role(Roles::Owner);
+        assert!(!stablecoin.is_owner(&owner));
+        assert!(stablecoin.is_owner(&nominee));
+    }
+
+    #[test]
+    fn test_enumerate_role_members() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let (owner_2, owner_3, owner_4) =
+            (env.get_account(8), env.get_account(9), env.get_account(6));
+        env.set_caller(owner);
+        stablecoin.add_owner(&owner_2);
+        stablecoin.add_owner(&owner_3);
+        stablecoin.add_owner(&owner_4);
+        assert_eq!(stablecoin.role_member_count(Roles::Owner), 4);
+        assert_eq!(
+            stablecoin.role_members(Roles::Owner, 0, 10),
+            vec![owner, owner_2, owner_3, owner_4]
+        );
+        assert_eq!(
+            stablecoin.role_members(Roles::Owner, 1, 2),
+            vec![owner_2, owner_3]
+        );
+        assert_eq!(stablecoin.role_members(Roles::Owner, 4, 2), vec![]);
+
+        // the last member takes the place of a removed one
+        stablecoin.remove_owner(&owner_2);
+        assert_eq!(stablecoin.role_member_count(Roles::Owner), 3);
+        assert_eq!(
+            stablecoin.role_members(Roles::Owner, 0, u32::MAX),
+            vec![owner, owner_4, owner_3]
+        );
+
+        env.set_caller(env.get_account(4));
+        stablecoin.blacklist(&owner_3);
+        assert_eq!(
+            stablecoin.role_members(Roles::Blacklisted, 0, 10),
+            vec![owner_3]
+        );
+        stablecoin.unblacklist(&owner_3);
+        assert_eq!(stablecoin.role_member_count(Roles::Blacklisted), 0);
+    }
+
+    #[test]
+    fn test_custom_role_hierarchy() {
+        let (env, .., owner, mut stablecoin) = setup();
+        let operator: [u8; 32] = [101u8; 32];
+        let operator_admin: [u8; 32] = [102u8; 32];
+        let (admin, user) = (env.get_account(8), env.get_account(6));
+        assert_eq!(stablecoin.role_admin(operator), Roles::Owner);
+        env.set_caller(owner);
+        let result = stablecoin.try_grant_role(Roles::Minter, &user);
+        assert_eq!(result.err().unwrap(), ReservedRole.into());
+        stablecoin.grant_role(operator_admin, &admin);
+        stablecoin.set_role_admin(operator, operator_admin);
+        assert!(
+            env.emitted(&stablecoin, "RoleAdminChanged"),
+            "RoleAdminChanged event not emitted"
+        );
+        assert_eq!(stablecoin.role_admin(operator), operator_admin);
+
+        // the Owner no longer administers the role
+        let result = stablecoin.try_grant_role(operator, &user);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(admin);
+        stablecoin.grant_role(operator, &user);
+        assert!(stablecoin.has_role(operator, &user));
+        stablecoin.revoke_role(operator, &user);
+        assert!(!stablecoin.has_role(operator, &user));
+        stablecoin.grant_role(operator, &user);
+
+        env.set_caller(user);
+        let result = stablecoin.try_renounce_role(Roles::Blacklisted);
+        assert_eq!(result.err().unwrap(), ReservedRole.into());
+        stablecoin.renounce_role(operator);
+        assert!(!stablecoin.has_role(operator, &user));
+    }
+
+    #[test]
+    fn test_role_transfer_expires() {
+        let (env, master_minter, _, owner, mut stablecoin) = setup();
+        let nominee = env.get_account(8);
+        env.set_caller(owner);
+        stablecoin.update_master_minter(&master_minter, &nominee);
+        env.advance_block_time(ROLE_TRANSFER_LIFETIME + 1);
+        env.set_caller(nominee);
+        let result = stablecoin.try_accept_role(Roles::MasterMinter);
+        assert_eq!(result.err().unwrap(), RoleTransferExpired.into());
+        assert!(stablecoin.is_master_minter(&master_minter));
+
+        // a lapsed nomination does not block a new one
+        env.set_caller(owner);
+        stablecoin.update_master_minter(&master_minter, &nominee);
+        env.set_caller(nominee);
+        stablecoin.accept_role(Roles::MasterMinter);
+        assert!(stablecoin.is_master_minter(&nominee));
+    }
+
+    #[test]
+    fn test_owner_cancels_or_voids_role_transfer() {
+        let (env, _, pauser, owner, mut stablecoin) = setup();
+        let (owner_2, nominee) = (env.get_account(8), env.get_account(9));
+        env.set_caller(owner);
+        stablecoin.add_owner(&owner_2);
+        env.set_caller(owner_2);
+        stablecoin.update_pauser(&pauser, &nominee);
+        // any Owner can cancel the nomination
+        env.set_caller(owner);
+        stablecoin.cancel_role_transfer(Roles::Pauser, &nominee);
+        assert!(stablecoin
+            .pending_role_transfer(Roles::Pauser, &nominee)
+            .is_none());
+
+        // the nomination lapses once its proposer is no longer an Owner
+        env.set_caller(owner_2);
+        stablecoin.update_pauser(&pauser, &nominee);
+        env.set_caller(owner);
+        stablecoin.remove_owner(&owner_2);
+        env.set_caller(nominee);
+        let result = stablecoin.try_accept_role(Roles::Pauser);
+        assert_eq!(result.err().unwrap(), RoleTransferExpired.into());
+        assert!(stablecoin.is_pausers(&pauser));
+        assert!(!stablecoin.is_pausers(&nominee));
+    }
+}
@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod signatures_tests {
+    use crate::stablecoin::errors::Error::{
+        AuthorizationExpired, AuthorizationNotYetValid, AuthorizationUsed, InvalidNonce,
+        InvalidPayee, InvalidSignature, PermitExpired,
+    };
+    use crate::stablecoin::setup_tests::setup;
+    use crate::stablecoin::utils::{
+        cancel_authorization_message, permit_message, receive_authorization_message,
+        transfer_authorization_message,
+    };
+    use crate::stablecoin::StablecoinHostRef;
+    use odra::casper_types::bytesrepr::Bytes;
+    use odra::casper_types::U256;
+    use odra::host::{HostEnv, HostRef};
+    use odra::Address;
+
+    const LIFETIME: u64 = 60_000;
+
+    fn sign_permit(
+        env: &HostEnv,
+        stablecoin: &StablecoinHostRef,
+        owner: &Address,
+        spender: &Address,
+        value: U256,
+        deadline: u64,
+        nonce: u64,
+    ) -> Bytes {
+        let message = permit_message(
+            stablecoin.address(),
+            owner,
+            spender,
+            &value,
+            deadline,
+            nonce,
+        )
+        .unwrap();
+        env.sign_message(&message, owner)
+    }
+
+    #[test]
+    fn test_permit() {
+        let (env, .., user, mut stablecoin) = setup();
+        // the deployer holds the initial supply
+        let owner = env.get_account(0);
+        let deadline = env.block_time() + LIFETIME;
+        let value = U256::from(100);
+        let signature = sign_permit(&env, &stablecoin, &owner, &user, value, deadline, 0);
+
+        // anyone can submit the signed permit
+        env.set_caller(user);
+        stablecoin.permit(
+            env.public_key(&owner),
+            &user,
+            value,
+            deadline,
+            0,
+            signature.clone(),
+        );
+        assert!(
+            env.emitted(&stablecoin, "SetAllowance"),
+            "SetAllowance event not emitted"
+        );
+        assert_eq!(stablecoin.allowance(&owner, &user), value);
+        assert_eq!(stablecoin.nonce(&owner), 1);
+
+        // a permit cannot be replayed, nor can nonces be skipped
+        let result =
+            stablecoin.try_permit(env.public_key(&owner), &user, value, deadline, 0, signature);
+        assert_eq!(result.err().unwrap(), InvalidNonce.into());
+        let signature = sign_permit(&env, &stablecoin, &owner, &user, value, deadline, 2);
+        let result =
+            stablecoin.try_permit(env.public_key(&owner), &user, value, deadline, 2, signature);
+        assert_eq!(result.err().unwrap(), InvalidNonce.into());
+    }
+
+    #[test]
+    fn test_permit_rejects_bad_signatures() {
+        let (env, .., user, mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let deadline = env.block_time() + LIFETIME;
+        let value = U256::from(100);
+
+        // signed by someone else
+        let signature = sign_permit(&env, &stablecoin, &user, &user, value, deadline, 0);
+        let result =
+            stablecoin.try_permit(env.public_key(&owner), &user, value, deadline, 0, signature);
+        assert_eq!(result.err().unwrap(), InvalidSignature.into());
+
+        // signed for a smaller value
+        let signature = sign_permit(&env, &stablecoin, &owner, &user, U256::one(), deadline, 0);
+        let result =
+            stablecoin.try_permit(env.public_key(&owner), &user, value, deadline, 0, signature);
+        assert_eq!(result.err().unwrap(), InvalidSignature.into());
+
+        let signature = sign_permit(&env, &stablecoin, &owner, &user, value, deadline, 0);
+        env.advance_block_time(LIFETIME + 1);
+        let result =
+            stablecoin.try_permit(env.public_key(&owner), &user, value, deadline, 0, signature);
+        assert_eq!(result.err().unwrap(), PermitExpired.into());
+        assert_eq!(stablecoin.allowance(&owner, &user), U256::zero());
+    }
+
+    #[test]
+    fn test_transfer_with_authorization() {
+        let (env, .., user, mut stablecoin) = setup();
+        // authorizations only become valid after `valid_after`
+        env.advance_block_time(1);
+        let from = env.get_account(0);
+        let recipient = env.get_account(7);
+        let valid_before = env.block_time() + LIFETIME;
+        let value = U256::from(100);
+        let nonce = [1u8; 32];
+        let message = transfer_authorization_message(
+            stablecoin.address(),
+            &from,
+            &recipient,
+            &value,
+            0,
+            valid_before,
+            &nonce,
+        )
+        .unwrap();
+        let signature = env.sign_message(&message, &from);
+        let from_balance = stablecoin.balance_of(&from);
+
+        // anyone can submit the signed authorization
+        env.set_caller(user);
+        stablecoin.transfer_with_authorization(
+            env.public_key(&from),
+            &recipient,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature.clone(),
+        );
+        assert!(
+            env.emitted(&stablecoin, "AuthorizationUsed"),
+            "AuthorizationUsed event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&recipient), value);
+        assert_eq!(stablecoin.balance_of(&from), from_balance - value);
+        assert!(stablecoin.authorization_state(&from, nonce));
+
+        let result = stablecoin.try_transfer_with_authorization(
+            env.public_key(&from),
+            &recipient,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature,
+        );
+        assert_eq!(result.err().unwrap(), AuthorizationUsed.into());
+    }
+
+    #[test]
+    fn test_authorization_validity_window() {
+        let (env, .., user, mut stablecoin) = setup();
+        let from = env.get_account(0);
+        let valid_after = env.block_time() + LIFETIME;
+        let valid_before = valid_after + LIFETIME;
+        let value = U256::from(100);
+        let nonce = [2u8; 32];
+        let message = transfer_authorization_message(
+            stablecoin.address(),
+            &from,
+            &user,
+            &value,
+            valid_after,
+            valid_before,
+            &nonce,
+        )
+        .unwrap();
+        let signature = env.sign_message(&message, &from);
+
+        let result = stablecoin.try_transfer_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            valid_after,
+            valid_before,
+            nonce,
+            signature.clone(),
+        );
+        assert_eq!(result.err().unwrap(), AuthorizationNotYetValid.into());
+
+        env.advance_block_time(2 * LIFETIME);
+        let result = stablecoin.try_transfer_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            valid_after,
+            valid_before,
+            nonce,
+            signature,
+        );
+        assert_eq!(result.err().unwrap(), AuthorizationExpired.into());
+        assert!(!stablecoin.authorization_state(&from, nonce));
+    }
+
+    #[test]
+    fn test_receive_with_authorization() {
+        let (env, .., user, mut stablecoin) = setup();
+        let from = env.get_account(0);
+        let valid_before = env.block_time() + LIFETIME;
+        env.advance_block_time(1);
+        let value = U256::from(100);
+        let nonce = [3u8; 32];
+        let message = receive_authorization_message(
+            stablecoin.address(),
+            &from,
+            &user,
+            &value,
+            0,
+            valid_before,
+            &nonce,
+        )
+        .unwrap();
+        let signature = env.sign_message(&message, &from);
+
+        // only the recipient can submit it
+        env.set_caller(env.get_account(7));
+        let result = stablecoin.try_receive_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature.clone(),
+        );
+        assert_eq!(result.err().unwrap(), InvalidPayee.into());
+
+        // a receive authorization cannot be submitted as a transfer authorization
+        let result = stablecoin.try_transfer_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature.clone(),
+        );
+        assert_eq!(result.err().unwrap(), InvalidSignature.into());
+
+        env.set_caller(user);
+        stablecoin.receive_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature,
+        );
+        assert_eq!(stablecoin.balance_of(&user), value);
+    }
+
+    #[test]
+    fn test_cancel_authorization() {
+        let (env, .., user, mut stablecoin) = setup();
+        let from = env.get_account(0);
+        let valid_before = env.block_time() + LIFETIME;
+        env.advance_block_time(1);
+        let value = U256::from(100);
+        let nonce = [4u8; 32];
+        let message = transfer_authorization_message(
+            stablecoin.address(),
+            &from,
+            &user,
+            &value,
+            0,
+            valid_before,
+            &nonce,
+        )
+        .unwrap();
+        let signature = env.sign_message(&message, &from);
+
+        // the cancellation must be signed by the authorizer
+        let cancel_message =
+            cancel_authorization_message(stablecoin.address(), &from, &nonce).unwrap();
+        let result = stablecoin.try_cancel_authorization(
+            env.public_key(&from),
+            nonce,
+            env.sign_message(&cancel_message, &user),
+        );
+        assert_eq!(result.err().unwrap(), InvalidSignature.into());
+
+        stablecoin.cancel_authorization(
+            env.public_key(&from),
+            nonce,
+            env.sign_message(&cancel_message, &from),
+        );
+        assert!(
+            env.emitted(&stablecoin, "AuthorizationCanceled"),
+            "AuthorizationCanceled event not emitted"
+        );
+        assert!(stablecoin.authorization_state(&from, nonce));
+
+        let result = stablecoin.try_transfer_with_authorization(
+            env.public_key(&from),
+            &user,
+            value,
+            0,
+            valid_before,
+            nonce,
+            signature,
+        );
+        assert_eq!(result.err().unwrap(), AuthorizationUsed.into());
+        assert_eq!(stablecoin.balance_of(&user), U256::zero());
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod timelock_tests {
+    use crate::stablecoin::errors::Error::{
+        InsufficientRights, MinterNotControlled, MissingQueuedAction, TimelockNotElapsed,
+    };
+    use crate::stablecoin::setup_tests::setup_with;
+    use crate::stablecoin::storage::Roles;
+    use crate::stablecoin::utils::{QueuedActionStatus, StablecoinModality};
+    use crate::stablecoin::StablecoinHostRef;
+    use alloc::vec;
+    use odra::casper_types::U256;
+    use odra::host::HostEnv;
+    use odra::Address;
+
+    const DELAY: u64 = 86_400_000;
+    const ALLOWANCE_THRESHOLD: u64 = 1_000;
+
+    fn setup() -> (HostEnv, Address, Address, Address, StablecoinHostRef) {
+        let env = odra_test::env();
+        let master_minter = env.get_account(1);
+        let pauser = env.get_account(5);
+        let owner = env.get_account(7);
+        let guardian = env.get_account(8);
+        let mut stablecoin = setup_with(&env, vec![owner], StablecoinModality::MintAndBurn);
+        env.set_caller(owner);
+        stablecoin.add_guardian(&guardian);
+        stablecoin.configure_timelock(DELAY, ALLOWANCE_THRESHOLD.into());
+        (env, master_minter, pauser, guardian, stablecoin)
+    }
+
+    #[test]
+    fn test_configure_controller_is_delayed() {
+        let (env, master_minter, .., mut stablecoin) = setup();
+        let controller = env.get_account(2);
+        let minter = env.get_account(3);
+        assert_eq!(stablecoin.timelock_delay(), DELAY);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller, &minter);
+        assert!(
+            env.emitted(&stablecoin, "ActionQueued"),
+            "ActionQueued event not emitted"
+        );
+        assert!(!stablecoin.is_minter(&minter));
+        let result = stablecoin.try_execute_queued_action(0);
+        assert_eq!(result.err().unwrap(), TimelockNotElapsed.into());
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(0);
+        assert!(
+            env.emitted(&stablecoin, "ActionExecuted"),
+            "ActionExecuted event not emitted"
+        );
+        assert!(stablecoin.is_minter(&minter));
+        assert_eq!(
+            stablecoin.queued_action(0).unwrap().status,
+            QueuedActionStatus::Executed
+        );
+        let result = stablecoin.try_execute_queued_action(0);
+        assert_eq!(result.err().unwrap(), MissingQueuedAction.into());
+    }
+
+    #[test]
+    fn test_only_large_allowances_are_delayed() {
+        let (env, master_minter, .., mut stablecoin) = setup();
+        let controller = env.get_account(2);
+        let minter = env.get_account(3);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller, &minter);
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(0);
+        env.set_caller(controller);
+        stablecoin.configure_minter_allowance(&minter, ALLOWANCE_THRESHOLD.into());
+        assert_eq!(
+            stablecoin.minter_allowance(&minter),
+            ALLOWANCE_THRESHOLD.into()
+        );
+        stablecoin.increase_minter_allowance(&minter, U256::one());
+        assert_eq!(
+            stablecoin.minter_allowance(&minter),
+            ALLOWANCE_THRESHOLD.into()
+        );
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(1);
+        assert_eq!(
+            stablecoin.minter_allowance(&minter),
+            (ALLOWANCE_THRESHOLD + 1).into()
+        );
+    }
+
+    #[test]
+    fn test_guardian_cancels_unpause() {
+        let (env, _, pauser, guardian, mut stablecoin) = setup();
+        env.set_caller(pauser);
+        stablecoin.pause();
+        stablecoin.unpause();
+        env.advance_block_time(DELAY);
+        let result = stablecoin.try_cancel_queued_action(0);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        env.set_caller(guardian);
+        stablecoin.cancel_queued_action(0);
+        assert!(
+            env.emitted(&stablecoin, "ActionCancelled"),
+            "ActionCancelled event not emitted"
+        );
+        let result = stablecoin.try_execute_queued_action(0);
+        assert_eq!(result.err().unwrap(), MissingQueuedAction.into());
+        // the contract is still paused
+        let result = stablecoin.try_transfer(&guardian, &U256::zero());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_guardian_cannot_cancel_own_removal() {
+        let (env, _, pauser, guardian, mut stablecoin) = setup();
+        let (owner, guardian_2) = (env.get_account(7), env.get_account(9));
+        env.set_caller(owner);
+        stablecoin.add_guardian(&guardian_2);
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(0);
+        stablecoin.remove_guardian(&guardian);
+        assert!(stablecoin.has_role(Roles::Guardian, &guardian));
+        env.set_caller(guardian);
+        let result = stablecoin.try_cancel_queued_action(1);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        // another Guardian can still stop the removal
+        env.set_caller(guardian_2);
+        stablecoin.cancel_queued_action(1);
+        assert!(stablecoin.has_role(Roles::Guardian, &guardian));
+
+        env.set_caller(owner);
+        stablecoin.remove_guardian(&guardian);
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(2);
+        assert!(!stablecoin.has_role(Roles::Guardian, &guardian));
+        env.set_caller(pauser);
+        stablecoin.pause();
+        stablecoin.unpause();
+        env.set_caller(guardian);
+        let result = stablecoin.try_cancel_queued_action(3);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+    }
+
+    #[test]
+    fn test_queued_allowance_needs_controller_at_execution() {
+        let (env, master_minter, .., mut stablecoin) = setup();
+        let controller = env.get_account(2);
+        let minter = env.get_account(3);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&controller, &minter);
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(0);
+        env.set_caller(controller);
+        stablecoin.configure_minter_allowance(&minter, (ALLOWANCE_THRESHOLD + 1).into());
+        env.set_caller(master_minter);
+        stablecoin.remove_controller(&controller, false);
+
+        env.advance_block_time(DELAY);
+        let result = stablecoin.try_execute_queued_action(1);
+        assert_eq!(result.err().unwrap(), MinterNotControlled.into());
+        assert_eq!(stablecoin.minter_allowance(&minter), U256::zero());
+    }
+
+    #[test]
+    fn test_ready_time_saturates() {
+        let (env, master_minter, .., mut stablecoin) = setup();
+        let owner = env.get_account(7);
+        env.set_caller(owner);
+        stablecoin.configure_timelock(u64::MAX, ALLOWANCE_THRESHOLD.into());
+        env.advance_block_time(DELAY);
+        stablecoin.execute_queued_action(0);
+        assert_eq!(stablecoin.timelock_delay(), u64::MAX);
+        env.set_caller(master_minter);
+        stablecoin.configure_controller(&env.get_account(2), &env.get_account(3));
+        assert_eq!(stablecoin.queued_action(1).unwrap().ready_at, u64::MAX);
+    }
+}
@@ -1,13 +1,24 @@
 #[cfg(test)]
 mod transfer_tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use odra::casper_types::bytesrepr::Bytes;
     use odra::casper_types::U256;
     use odra::host::{Deployer, HostRef, NoArgs};
 
-    use crate::stablecoin::errors::Error::{CannotTargetSelfUser, InsufficientBalance};
+    use crate::stablecoin::errors::Error::{
+        CannotTargetSelfUser, InsufficientAllowance, InsufficientBalance, InsufficientRights,
+        MemoTooLong, TransferRejected,
+    };
+    use crate::stablecoin::events::TransferWithMemo;
     use crate::stablecoin::tests::client_contract_test::StablecoinClientContractHostRef;
+    use crate::stablecoin::tests::receiver_contract_test::{
+        StablecoinReceiverContractHostRef, StablecoinReceiverContractInitArgs,
+    };
     use crate::stablecoin::setup_tests::{
         setup, ALLOWANCE_AMOUNT_1, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
     };
+    use crate::stablecoin::utils::MAX_MEMO_LENGTH;
 
     #[test]
     fn should_transfer_full_owned_amount() {
@@ -187,4 +198,165 @@ mod transfer_tests {
             TRANSFER_AMOUNT_1.into()
         );
     }
+
+    #[test]
+    fn should_transfer_batch() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let alice = env.get_account(1);
+        let bob = env.get_account(2);
+        stablecoin.transfer_batch(vec![(alice, 100.into()), (bob, 200.into())]);
+        assert_eq!(stablecoin.balance_of(&alice), 100.into());
+        assert_eq!(stablecoin.balance_of(&bob), 200.into());
+        assert_eq!(
+            stablecoin.balance_of(&owner),
+            (TOKEN_TOTAL_SUPPLY - 300).into()
+        );
+
+        // the batch is reverted as a whole
+        let amount: U256 = TOKEN_TOTAL_SUPPLY.into();
+        let result = stablecoin.try_transfer_batch(vec![(alice, 1.into()), (bob, amount)]);
+        assert_eq!(result.err().unwrap(), InsufficientBalance.into());
+        let result = stablecoin.try_transfer_batch(vec![(alice, 1.into()), (owner, 1.into())]);
+        assert_eq!(result.err().unwrap(), CannotTargetSelfUser.into());
+        assert_eq!(stablecoin.balance_of(&alice), 100.into());
+    }
+
+    #[test]
+    fn should_not_transfer_batch_to_blacklisted_recipient() {
+        let (env, _, _, _, blacklister, _, user, mut stablecoin) = setup();
+        let alice = env.get_account(1);
+        env.set_caller(blacklister);
+        stablecoin.blacklist(&user);
+        env.set_caller(env.get_account(0));
+        let result = stablecoin.try_transfer_batch(vec![(alice, 1.into()), (user, 1.into())]);
+        assert_eq!(result.err().unwrap(), InsufficientRights.into());
+        assert_eq!(stablecoin.balance_of(&alice), 0.into());
+    }
+
+    #[test]
+    fn should_transfer_from_batch() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let alice = env.get_account(1);
+        let bob = env.get_account(2);
+        let spender = env.get_account(7);
+        stablecoin.approve(&spender, &ALLOWANCE_AMOUNT_1.into());
+        env.set_caller(spender);
+
+        let result = stablecoin.try_transfer_from_batch(
+            &owner,
+            vec![(alice, ALLOWANCE_AMOUNT_1.into()), (bob, 1.into())],
+        );
+        assert_eq!(result.err().unwrap(), InsufficientAllowance.into());
+
+        stablecoin.transfer_from_batch(&owner, vec![(alice, 100.into()), (bob, 200.into())]);
+        assert!(
+            stablecoin.env().emitted(&stablecoin, "TransferFrom"),
+            "TransferFrom event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&alice), 100.into());
+        assert_eq!(stablecoin.balance_of(&bob), 200.into());
+        assert_eq!(
+            stablecoin.allowance(&owner, &spender),
+            (ALLOWANCE_AMOUNT_1 - 300).into()
+        );
+    }
+
+    #[test]
+    fn should_transfer_with_memo() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let alice = env.get_account(1);
+        let amount: U256 = TRANSFER_AMOUNT_1.into();
+        stablecoin.transfer_with_memo(&alice, &amount, "invoice-42".to_string());
+        assert!(env.emitted_event(
+            &stablecoin,
+            &TransferWithMemo {
+                sender: owner,
+                recipient: alice,
+                amount,
+                memo: "invoice-42".to_string(),
+            }
+        ));
+        assert!(
+            env.emitted(&stablecoin, "Transfer"),
+            "Transfer event not emitted"
+        );
+        assert_eq!(stablecoin.balance_of(&alice), amount);
+
+        let memo = "x".repeat(MAX_MEMO_LENGTH + 1);
+        let result = stablecoin.try_transfer_with_memo(&alice, &amount, memo);
+        assert_eq!(result.err().unwrap(), MemoTooLong.into());
+    }
+
+    #[test]
+    fn should_transfer_from_with_memo() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let alice = env.get_account(1);
+        let amount: U256 = TRANSFER_AMOUNT_1.into();
+        stablecoin.approve(&alice, &ALLOWANCE_AMOUNT_1.into());
+        env.set_caller(alice);
+        stablecoin.transfer_from_with_memo(&owner, &alice, &amount, "deposit-7".to_string());
+        assert!(env.emitted_event(
+            &stablecoin,
+            &TransferWithMemo {
+                sender: owner,
+                recipient: alice,
+                amount,
+                memo: "deposit-7".to_string(),
+            }
+        ));
+        assert_eq!(stablecoin.balance_of(&alice), amount);
+
+        // the spender's allowance is used up, not the recipient's
+        let bob = env.get_account(2);
+        stablecoin.transfer_from_with_memo(&owner, &bob, &amount, "deposit-8".to_string());
+        assert_eq!(stablecoin.balance_of(&bob), amount);
+        assert_eq!(
+            stablecoin.allowance(&owner, &alice),
+            U256::from(ALLOWANCE_AMOUNT_1) - amount - amount
+        );
+        assert_eq!(stablecoin.allowance(&owner, &bob), U256::zero());
+    }
+
+    #[test]
+    fn should_transfer_and_call() {
+        let (env, .., mut stablecoin) = setup();
+        let owner = env.get_account(0);
+        let alice = env.get_account(1);
+        let amount: U256 = TRANSFER_AMOUNT_1.into();
+        let receiver = StablecoinReceiverContractHostRef::deploy(
+            &env,
+            StablecoinReceiverContractInitArgs { accept: true },
+        );
+        let data = Bytes::from(vec![1, 2, 3]);
+        stablecoin.transfer_and_call(receiver.address(), &amount, data.clone());
+        assert_eq!(stablecoin.balance_of(receiver.address()), amount);
+        assert_eq!(receiver.received(), amount);
+        assert_eq!(receiver.last_from(), Some(owner));
+        assert_eq!(receiver.last_data(), data);
+
+        // accounts are not notified
+        stablecoin.transfer_and_call(&alice, &amount, data);
+        assert_eq!(stablecoin.balance_of(&alice), amount);
+        assert_eq!(
+            stablecoin.balance_of(&owner),
+            U256::from(TOKEN_TOTAL_SUPPLY) - amount - amount
+        );
+    }
+
+    #[test]
+    fn should_not_transfer_and_call_rejecting_receiver() {
+        let (env, .., mut stablecoin) = setup();
+        let amount: U256 = TRANSFER_AMOUNT_1.into();
+        let receiver = StablecoinReceiverContractHostRef::deploy(
+            &env,
+            StablecoinReceiverContractInitArgs { accept: false },
+        );
+        let result = stablecoin.try_transfer_and_call(receiver.address(), &amount, Bytes::new());
+        assert_eq!(result.err().unwrap(), TransferRejected.into());
+        assert_eq!(stablecoin.balance_of(receiver.address()), 0.into());
+    }
 }
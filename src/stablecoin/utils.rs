@@ -1,3 +1,9 @@
+use crate::stablecoin::storage::Roles::Role;
+use odra::casper_types::bytesrepr::{self, Bytes, ToBytes};
+use odra::casper_types::U256;
+use odra::prelude::*;
+use odra::Address;
+
 /// Modality of the CEP-18 contract.
 #[derive(Default)]
 #[odra::odra_type]
@@ -7,12 +13,22 @@ pub enum StablecoinModality {
     None = 0,
     /// The contract can mint and burn tokens.
     MintAndBurn = 1,
+    /// The contract can mint and burn tokens, only allowlisted accounts can hold and receive them.
+    Allowlist = 2,
 }
 
 impl StablecoinModality {
     /// Returns true if the mint and burn functionality is enabled.
     pub fn mint_and_burn_enabled(&self) -> bool {
-        matches!(self, StablecoinModality::MintAndBurn)
+        matches!(
+            self,
+            StablecoinModality::MintAndBurn | StablecoinModality::Allowlist
+        )
+    }
+
+    /// Returns true if only allowlisted accounts can hold and receive tokens.
+    pub fn allowlist_enabled(&self) -> bool {
+        matches!(self, StablecoinModality::Allowlist)
     }
 }
 
@@ -22,3 +38,377 @@ impl From<StablecoinModality> for u8 {
         modality as u8
     }
 }
+
+/// An operation that can be paused on its own.
+#[odra::odra_type]
+pub enum PausableOperation {
+    /// Token transfers.
+    Transfer = 0,
+    /// Minting new tokens.
+    Mint = 1,
+    /// Burning tokens.
+    Burn = 2,
+    /// Setting and changing allowances.
+    Approve = 3,
+}
+
+/// Which operations are currently paused.
+#[derive(Default)]
+#[odra::odra_type]
+pub struct PauseState {
+    /// Token transfers are paused.
+    pub transfer: bool,
+    /// Minting is paused.
+    pub mint: bool,
+    /// Burning is paused.
+    pub burn: bool,
+    /// Setting and changing allowances is paused.
+    pub approve: bool,
+}
+
+impl PauseState {
+    /// Returns true if the operation is paused.
+    pub fn is_paused(&self, operation: &PausableOperation) -> bool {
+        match operation {
+            PausableOperation::Transfer => self.transfer,
+            PausableOperation::Mint => self.mint,
+            PausableOperation::Burn => self.burn,
+            PausableOperation::Approve => self.approve,
+        }
+    }
+
+    /// Pauses or unpauses the operation.
+    pub fn set(&mut self, operation: &PausableOperation, paused: bool) {
+        match operation {
+            PausableOperation::Transfer => self.transfer = paused,
+            PausableOperation::Mint => self.mint = paused,
+            PausableOperation::Burn => self.burn = paused,
+            PausableOperation::Approve => self.approve = paused,
+        }
+    }
+
+    /// Returns the state in which every operation is paused or unpaused.
+    pub fn all(paused: bool) -> Self {
+        Self {
+            transfer: paused,
+            mint: paused,
+            burn: paused,
+            approve: paused,
+        }
+    }
+}
+
+/// A role handover that awaits acceptance by the nominee.
+#[odra::odra_type]
+pub struct PendingRoleTransfer {
+    /// The account that proposed the handover.
+    pub proposer: Address,
+    /// The account that gives up the role once the handover is accepted.
+    pub previous_holder: Address,
+    /// Block time after which the nomination can no longer be accepted.
+    pub expires_at: u64,
+}
+
+/// Why and until when an account is blacklisted.
+#[derive(Default)]
+#[odra::odra_type]
+pub struct BlacklistEntry {
+    /// Compliance reason code, zero if unspecified.
+    pub reason_code: u32,
+    /// Off-chain reference such as a case id.
+    pub reference: String,
+    /// Block time from which the restriction lapses, `None` if it never does.
+    pub expires_at: Option<u64>,
+}
+
+/// The mint rate limit of a minter, tracked as a bucket that drains continuously.
+#[odra::odra_type]
+pub struct MintWindow {
+    /// The time in milliseconds it takes a full bucket to drain.
+    pub duration: u64,
+    /// The capacity of the bucket, which drains at `limit / duration`.
+    pub limit: U256,
+    /// The block time at which `used` was last updated.
+    pub updated_at: u64,
+    /// The recently minted amount that has not drained yet.
+    pub used: U256,
+}
+
+impl MintWindow {
+    /// Returns the part of the bucket that has not drained by the given block time.
+    pub fn used_at(&self, now: u64) -> U256 {
+        let elapsed = U256::from(now.saturating_sub(self.updated_at));
+        let drained = self.limit.saturating_mul(elapsed) / U256::from(self.duration);
+        self.used.saturating_sub(drained)
+    }
+}
+
+/// A privileged operation that is carried out once enough Owners approve it.
+#[odra::odra_type]
+pub enum AdminAction {
+    /// Pair a controller with a minter.
+    ConfigureController {
+        controller: Address,
+        minter: Address,
+    },
+    /// Remove a controller, optionally removing its minters.
+    RemoveController {
+        controller: Address,
+        remove_minters: bool,
+    },
+    /// Pause the contract.
+    Pause,
+    /// Unpause the contract.
+    Unpause,
+    /// Unpause a single operation.
+    UnpauseOperation { operation: PausableOperation },
+    /// Blacklist an account.
+    Blacklist { account: Address },
+    /// Remove an account from the Blacklist.
+    Unblacklist { account: Address },
+    /// Burn the balance of a blacklisted account, or move it to `recovery` when given.
+    Seize {
+        account: Address,
+        recovery: Option<Address>,
+    },
+    /// Nominate a new Blacklister.
+    UpdateBlacklister { new_blacklister: Address },
+    /// Nominate a replacement for a MasterMinter.
+    UpdateMasterMinter {
+        old_master_minter: Address,
+        new_master_minter: Address,
+    },
+    /// Nominate a replacement for a Pauser.
+    UpdatePauser {
+        old_pauser: Address,
+        new_pauser: Address,
+    },
+    /// Add an Owner.
+    AddOwner { account: Address },
+    /// Remove an Owner.
+    RemoveOwner { account: Address },
+    /// Change the number of Owner approvals a proposal needs.
+    SetProposalThreshold { threshold: u32 },
+    /// Hand a role over from its previous holder to a new one.
+    /// Queued once a nominee accepts a role, it cannot be proposed.
+    HandOverRole {
+        role: Role,
+        previous_holder: Address,
+        new_holder: Address,
+    },
+    /// Set the allowance of a minter.
+    ConfigureMinterAllowance {
+        minter: Address,
+        minter_allowance: U256,
+    },
+    /// Increase the allowance of a minter.
+    IncreaseMinterAllowance { minter: Address, increment: U256 },
+    /// Add a Guardian.
+    AddGuardian { account: Address },
+    /// Remove a Guardian.
+    RemoveGuardian { account: Address },
+    /// Add an Allowlister.
+    AddAllowlister { account: Address },
+    /// Remove an Allowlister.
+    RemoveAllowlister { account: Address },
+    /// Change the timelock delay and the minter allowance above which it applies.
+    ConfigureTimelock {
+        delay: u64,
+        allowance_threshold: U256,
+    },
+    /// Change the maximum supply of the token.
+    SetMaxSupply { max_supply: U256 },
+    /// Grant a custom role.
+    GrantRole { role: Role, account: Address },
+    /// Revoke a custom role.
+    RevokeRole { role: Role, account: Address },
+    /// Change the admin role of a custom role.
+    SetRoleAdmin { role: Role, admin_role: Role },
+}
+
+impl AdminAction {
+    /// Returns the minter whose allowance the action changes, if any.
+    pub fn allowance_minter(&self) -> Option<Address> {
+        match self {
+            AdminAction::ConfigureMinterAllowance { minter, .. }
+            | AdminAction::IncreaseMinterAllowance { minter, .. } => Some(*minter),
+            _ => None,
+        }
+    }
+}
+
+/// Lifecycle state of a proposal.
+#[odra::odra_type]
+pub enum ProposalStatus {
+    /// The proposal is collecting approvals.
+    Pending = 0,
+    /// The proposed action has been carried out.
+    Executed = 1,
+    /// The proposer withdrew the proposal.
+    Cancelled = 2,
+}
+
+/// An admin action proposed by an Owner.
+#[odra::odra_type]
+pub struct Proposal {
+    /// The action to carry out.
+    pub action: AdminAction,
+    /// The Owner that proposed the action.
+    pub proposer: Address,
+    /// The number of current Owners that had approved the action at its last approval.
+    pub approvals: u32,
+    /// Block time after which the proposal can no longer be approved.
+    pub expires_at: u64,
+    /// The lifecycle state of the proposal.
+    pub status: ProposalStatus,
+}
+
+/// Lifecycle state of an action queued by the timelock.
+#[odra::odra_type]
+pub enum QueuedActionStatus {
+    /// The action waits for its delay to pass.
+    Queued = 0,
+    /// The action has been carried out.
+    Executed = 1,
+    /// A Guardian cancelled the action.
+    Cancelled = 2,
+}
+
+/// Lifecycle state of a redemption request.
+#[odra::odra_type]
+pub enum RedemptionStatus {
+    /// The tokens are held in escrow until a minter processes the request.
+    Pending = 0,
+    /// A minter burned the escrowed tokens.
+    Finalized = 1,
+    /// A minter refunded the escrowed tokens to the holder.
+    Rejected = 2,
+}
+
+/// A holder's request to redeem tokens held in escrow by the contract.
+#[odra::odra_type]
+pub struct Redemption {
+    /// The account that requested the redemption.
+    pub holder: Address,
+    /// The amount held in escrow.
+    pub amount: U256,
+    /// Off-chain reference linking the request to the payout.
+    pub reference: String,
+    /// The lifecycle state of the request.
+    pub status: RedemptionStatus,
+}
+
+/// A sensitive admin action that waits for the timelock delay.
+#[odra::odra_type]
+pub struct QueuedAction {
+    /// The action to carry out.
+    pub action: AdminAction,
+    /// Block time from which the action can be carried out.
+    pub ready_at: u64,
+    /// The lifecycle state of the action.
+    pub status: QueuedActionStatus,
+    /// The controller that requested a minter allowance change, which must still control
+    /// the minter when the action is carried out.
+    pub controller: Option<Address>,
+}
+
+/// How long a role handover nomination can be accepted, in milliseconds.
+pub const ROLE_TRANSFER_LIFETIME: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// The maximum length in bytes of a transfer memo.
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+/// Domain tag that keeps permit signatures from being valid for any other message.
+pub const PERMIT_DOMAIN: &[u8] = b"stablecoin-casper:permit";
+
+/// Builds the message an owner signs to let `spender` spend `value` of their tokens
+/// through `permit` on the `token` contract.
+pub fn permit_message(
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    value: &U256,
+    deadline: u64,
+    nonce: u64,
+) -> Result<Bytes, bytesrepr::Error> {
+    let mut message = PERMIT_DOMAIN.to_vec();
+    message.append(&mut (*token, *owner, *spender, *value, deadline, nonce).to_bytes()?);
+    Ok(Bytes::from(message))
+}
+
+/// Domain tag of the messages signed for `transfer_with_authorization`.
+pub const TRANSFER_WITH_AUTHORIZATION_DOMAIN: &[u8] =
+    b"stablecoin-casper:transfer_with_authorization";
+
+/// Domain tag of the messages signed for `receive_with_authorization`.
+pub const RECEIVE_WITH_AUTHORIZATION_DOMAIN: &[u8] =
+    b"stablecoin-casper:receive_with_authorization";
+
+/// Domain tag of the messages signed for `cancel_authorization`.
+pub const CANCEL_AUTHORIZATION_DOMAIN: &[u8] = b"stablecoin-casper:cancel_authorization";
+
+/// Builds the message `from` signs to let anyone submit a transfer of `value` to `to`
+/// through `transfer_with_authorization` on the `token` contract.
+pub fn transfer_authorization_message(
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    value: &U256,
+    valid_after: u64,
+    valid_before: u64,
+    nonce: &[u8; 32],
+) -> Result<Bytes, bytesrepr::Error> {
+    authorization_message(
+        TRANSFER_WITH_AUTHORIZATION_DOMAIN,
+        (
+            *token,
+            *from,
+            *to,
+            *value,
+            valid_after,
+            valid_before,
+            *nonce,
+        ),
+    )
+}
+
+/// Builds the message `from` signs to let `to` collect a transfer of `value`
+/// through `receive_with_authorization` on the `token` contract.
+pub fn receive_authorization_message(
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    value: &U256,
+    valid_after: u64,
+    valid_before: u64,
+    nonce: &[u8; 32],
+) -> Result<Bytes, bytesrepr::Error> {
+    authorization_message(
+        RECEIVE_WITH_AUTHORIZATION_DOMAIN,
+        (
+            *token,
+            *from,
+            *to,
+            *value,
+            valid_after,
+            valid_before,
+            *nonce,
+        ),
+    )
+}
+
+/// Builds the message an authorizer signs to cancel an unused authorization through
+/// `cancel_authorization` on the `token` contract.
+pub fn cancel_authorization_message(
+    token: &Address,
+    authorizer: &Address,
+    nonce: &[u8; 32],
+) -> Result<Bytes, bytesrepr::Error> {
+    authorization_message(CANCEL_AUTHORIZATION_DOMAIN, (*token, *authorizer, *nonce))
+}
+
+fn authorization_message<T: ToBytes>(domain: &[u8], fields: T) -> Result<Bytes, bytesrepr::Error> {
+    let mut message = domain.to_vec();
+    message.append(&mut fields.to_bytes()?);
+    Ok(Bytes::from(message))
+}